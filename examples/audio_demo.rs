@@ -1,4 +1,6 @@
 use bili_player::{
+    fetch::credentials::CredentialStore,
+    library::{self, DEFAULT_DATABASE_URL, repository::MusicRepository},
     logger::init_logger,
     player::{audio_player::AudioPlayer, command::PlayerCommand},
 };
@@ -6,8 +8,9 @@ use bili_player::{
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     init_logger("info").await?;
-    let file = "musics.txt";
-    let (mut player, sender) = AudioPlayer::new(file).await?;
+    let pool = library::connect(DEFAULT_DATABASE_URL).await?;
+    let (mut player, sender) =
+        AudioPlayer::new(MusicRepository::new(pool), CredentialStore::default()).await?;
 
     // 启动播放器后台任务（注意语法！）
     tokio::spawn(async move {
@@ -1,4 +1,7 @@
-use bili_player::fetch::{network::fetch_video_data, verify::fetch_and_verify_audio_url};
+use bili_player::fetch::{
+    cdn::CdnHosts, network::fetch_video_data, stream::StreamSelector,
+    verify::fetch_and_verify_audio_stream,
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -7,9 +10,15 @@ async fn main() -> anyhow::Result<()> {
     // 获取视频数据（周杰伦的《青花瓷》）
     let video_data = fetch_video_data(&client, "BV16K411d7PR").await?;
     println!("Title: {:?}", video_data);
-    // 获取音频 URL
-    let audio_url =
-        fetch_and_verify_audio_url(&client, &video_data.bvid, &video_data.cid.to_string()).await?;
-    println!("Audio URL: {}", audio_url);
+    // 获取默认音质（192K 以内的 AAC）的音频 URL
+    let (stream, url) = fetch_and_verify_audio_stream(
+        &client,
+        &video_data.bvid,
+        &video_data.cid.to_string(),
+        &StreamSelector::default(),
+        &CdnHosts::default(),
+    )
+    .await?;
+    println!("Audio URL: {} ({:?})", url, stream.quality());
     Ok(())
 }
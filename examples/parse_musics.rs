@@ -0,0 +1,14 @@
+use bili_player::player::music_data::parse_music_file;
+
+fn main() -> anyhow::Result<()> {
+    // 逐行解析 musics.txt，打印解析失败的行号和原因
+    let results = parse_music_file("musics.txt")?;
+    let (musics, errors): (Vec<_>, Vec<_>) = results.into_iter().partition(Result::is_ok);
+    for music in musics.into_iter().flatten() {
+        println!("{}", music);
+    }
+    for error in errors.into_iter().filter_map(Result::err) {
+        eprintln!("{}", error);
+    }
+    Ok(())
+}
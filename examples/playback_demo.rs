@@ -1,4 +1,6 @@
+use bili_player::fetch::credentials::CredentialStore;
 use bili_player::logger::init_logger;
+use bili_player::player::event::EosEvent;
 use bili_player::player::model::MusicInfo;
 use bili_player::player::playback::PlaybackManager;
 use tokio::sync::mpsc;
@@ -24,14 +26,16 @@ async fn main() -> anyhow::Result<()> {
     };
 
     gstreamer::init().unwrap();
-    let (tx, mut rx) = mpsc::channel::<()>(1);
+    let (tx, mut rx) = mpsc::channel::<EosEvent>(1);
     let pipeline = gstreamer::Pipeline::new();
 
     let mut playback = PlaybackManager::new(pipeline, Some(tx));
     let client = reqwest::Client::new();
     // let volume_manager = VolumeManager::new(playback.pipeline.as_ref());
     // let volume = volume_manager.get_gstreamer_volume();
-    playback.play_music(&client, &music_info, 0.10).await?;
+    playback
+        .play_music(&client, &music_info, 0.10, &CredentialStore::default())
+        .await?;
     // 阻塞等待播放结束
     if let Some(msg) = rx.recv().await {
         println!("Received message: {:?}", msg);
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS update_playlists_timestamp;
+DROP INDEX IF EXISTS idx_playlist_items_position;
+DROP TABLE IF EXISTS playlist_items;
+DROP TABLE IF EXISTS playlists;
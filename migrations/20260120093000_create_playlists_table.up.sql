@@ -0,0 +1,52 @@
+-- Add up migration script here
+-- 歌单表：用户自建的命名歌单，完整的音乐库（musics 表）作为默认歌单，不在此表中
+-- 设计说明：
+-- - name 唯一，作为用户操作歌单时的标识
+-- - last_bvid 记录该歌单上次播放到的歌曲，切换回来时从这里继续
+
+CREATE TABLE IF NOT EXISTS playlists (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- 歌单名称，唯一
+    name TEXT NOT NULL UNIQUE CHECK (length(name) BETWEEN 1 AND 255),
+
+    -- 上次播放到的歌曲 bvid（可为空）
+    last_bvid TEXT,
+
+    -- 创建时间
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+-- 歌单中的歌曲：引用 musics 表，按 position 排序
+CREATE TABLE IF NOT EXISTS playlist_items (
+    playlist_id INTEGER NOT NULL REFERENCES playlists(id) ON DELETE CASCADE,
+
+    music_id INTEGER NOT NULL REFERENCES musics(id) ON DELETE CASCADE,
+
+    -- 在歌单中的顺序，从 0 开始
+    position INTEGER NOT NULL CHECK (position >= 0),
+
+    -- 加入歌单的时间
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 同一首歌在一个歌单中只出现一次
+    PRIMARY KEY (playlist_id, music_id)
+);
+
+-- 按歌单顺序读取歌曲
+CREATE INDEX IF NOT EXISTS idx_playlist_items_position ON playlist_items(playlist_id, position);
+
+-- 自动更新 updated_at
+DROP TRIGGER IF EXISTS update_playlists_timestamp;
+
+CREATE TRIGGER update_playlists_timestamp
+AFTER UPDATE ON playlists
+WHEN OLD.updated_at = NEW.updated_at
+BEGIN
+    UPDATE playlists
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
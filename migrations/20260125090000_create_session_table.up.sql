@@ -0,0 +1,30 @@
+-- Add up migration script here
+-- 会话表：保存播放器的当前状态，服务重启后从这里恢复
+-- 设计说明：
+-- - 只有一行（id 固定为 1），每次保存时覆盖
+-- - playlist_id 为空表示正在使用音乐库，歌单被删除时自动回到音乐库
+
+CREATE TABLE IF NOT EXISTS session (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+
+    -- 正在使用的歌单（为空表示音乐库）
+    playlist_id INTEGER REFERENCES playlists(id) ON DELETE SET NULL,
+
+    -- 当前歌曲 bvid（可为空）
+    current_bvid TEXT,
+
+    -- 播放模式：normal、shuffle、repeat、repeat_all
+    play_mode TEXT NOT NULL DEFAULT 'normal',
+
+    -- 随机播放完一轮后是否继续
+    shuffle_loop INTEGER NOT NULL DEFAULT 1 CHECK (shuffle_loop IN (0, 1)),
+
+    -- 音量百分比
+    volume INTEGER NOT NULL DEFAULT 10 CHECK (volume BETWEEN 0 AND 200),
+
+    -- 当前歌曲播放到的位置（毫秒）
+    position_ms INTEGER NOT NULL DEFAULT 0 CHECK (position_ms >= 0),
+
+    -- 最后保存时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
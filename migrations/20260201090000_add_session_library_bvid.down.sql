@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE session DROP COLUMN library_bvid;
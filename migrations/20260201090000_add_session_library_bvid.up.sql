@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- 会话表增加音乐库的播放位置
+-- 设计说明：
+-- - 使用命名歌单时 current_bvid 是歌单中的歌曲，音乐库上次播放到的歌曲记在 library_bvid
+-- - 重启后切换回音乐库时从这里继续
+
+ALTER TABLE session ADD COLUMN library_bvid TEXT;
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tonic::{
+    Request, Status,
+    codegen::http,
+    metadata::{Ascii, MetadataValue},
+    service::Interceptor,
+};
+
+use crate::errors::{PlayerError, PlayerResult};
+
+/// 携带访问令牌的请求头，值为 `Bearer <token>`
+pub const AUTHORIZATION: &str = "authorization";
+
+/// 访问令牌的权限范围
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scope {
+    Read, // 只能查询状态、播放列表和订阅事件
+    #[default]
+    Full, // 完全控制
+}
+
+/// 配置文件中的一个访问令牌
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TokenConfig {
+    pub token: String, // 令牌内容
+    #[serde(default)]
+    pub scope: Scope, // 权限范围，默认完全控制
+    #[serde(default)]
+    pub name: Option<String>, // 客户端名称，只用于日志
+}
+
+/// 只需要只读权限的方法，其余方法都需要完全控制权限
+pub const READ_ONLY_METHODS: &[&str] = &[
+    "GetState",
+    "ShowMusicPageInfo",
+    "Find",
+    "Subscribe",
+    "ListQueue",
+    "ListPlaylists",
+    "LoginStatus",
+];
+
+/// 请求的 gRPC 方法路径，例如 `/player.PlayerService/GetState`
+///
+/// 拦截器拿不到请求的 URI，由 `record_grpc_path` 提前放进请求的 extensions。
+#[derive(Debug, Clone)]
+pub struct GrpcPath(pub String);
+
+/// 记录请求的 gRPC 方法路径，供认证拦截器判断需要的权限
+pub fn record_grpc_path<B>(mut request: http::Request<B>) -> http::Request<B> {
+    let path = GrpcPath(request.uri().path().to_string());
+    request.extensions_mut().insert(path);
+    request
+}
+
+/// 服务端认证拦截器，没有配置任何令牌时放行所有请求
+#[derive(Debug, Clone, Default)]
+pub struct Authenticator {
+    tokens: Arc<Vec<TokenConfig>>,
+}
+impl Authenticator {
+    /// Authenticator 构造函数
+    pub fn new(tokens: Vec<TokenConfig>) -> Self {
+        Self {
+            tokens: Arc::new(tokens),
+        }
+    }
+    /// 是否需要认证
+    pub fn is_enabled(&self) -> bool {
+        !self.tokens.is_empty()
+    }
+    /// 调用 gRPC 方法需要的权限
+    pub fn required_scope(path: &str) -> Scope {
+        let method = path.rsplit('/').next().unwrap_or_default();
+        if READ_ONLY_METHODS.contains(&method) {
+            Scope::Read
+        } else {
+            Scope::Full
+        }
+    }
+    /// 查找与请求携带的令牌相同的配置
+    fn find(&self, token: &str) -> Option<&TokenConfig> {
+        self.tokens
+            .iter()
+            .find(|config| constant_time_eq(config.token.as_bytes(), token.as_bytes()))
+    }
+}
+impl Interceptor for Authenticator {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        if !self.is_enabled() {
+            return Ok(request);
+        }
+        let token = request
+            .metadata()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or_else(|| Status::unauthenticated("缺少访问令牌"))?;
+        let config = self
+            .find(token.trim())
+            .ok_or_else(|| Status::unauthenticated("无效的访问令牌"))?;
+        // 没有记录方法路径时按最高权限要求
+        let path = request.extensions().get::<GrpcPath>();
+        let required = path.map_or(Scope::Full, |path| Self::required_scope(&path.0));
+        if config.scope < required {
+            tracing::warn!(
+                "Client {} is not allowed to call {}",
+                config.name.as_deref().unwrap_or("unnamed"),
+                path.map_or("unknown method", |path| path.0.as_str())
+            );
+            return Err(Status::permission_denied("访问令牌没有控制播放器的权限"));
+        }
+        Ok(request)
+    }
+}
+
+/// 客户端拦截器，在每个请求上附带访问令牌
+#[derive(Debug, Clone, Default)]
+pub struct TokenInterceptor {
+    header: Option<MetadataValue<Ascii>>,
+}
+impl TokenInterceptor {
+    /// TokenInterceptor 构造函数，token 为 None 时不附带令牌
+    pub fn new(token: Option<&str>) -> PlayerResult<Self> {
+        let header = token
+            .map(|token| {
+                MetadataValue::try_from(format!("Bearer {}", token.trim()))
+                    .map_err(|_| PlayerError::Config("访问令牌包含非法字符".into()))
+            })
+            .transpose()?;
+        Ok(Self { header })
+    }
+}
+impl Interceptor for TokenInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(header) = &self.header {
+            request.metadata_mut().insert(AUTHORIZATION, header.clone());
+        }
+        Ok(request)
+    }
+}
+
+/// 比较令牌时耗时不随相同前缀的长度变化
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
@@ -1,9 +1,28 @@
+use bili_player::auth::TokenInterceptor;
+use bili_player::config::Config;
+use bili_player::fetch::passport::{Passport, QrLoginStatus, render_qrcode};
+use bili_player::fetch::stream::AudioQuality;
 use bili_player::pb::{
-    GetStateRequest, NextRequest, PauseRequest, PlayBvidRequest, PlayRequest, PreviousRequest,
-    ResumeRequest, SetModelRequest, SetVolumeRequest, ShowMusicPageInfoRequest, StopRequest,
+    self, AddPlaylistRequest, AddToPlaylistRequest, ClearQueueRequest, CreatePlaylistRequest,
+    DeletePlaylistRequest, DeletedRequest, FindRequest, GetStateRequest, GetStateResponse,
+    IndexRange, ListPlaylistsRequest, ListQueueRequest, LoginStatusRequest, MoveTrackRequest,
+    NextRequest, PauseRequest, PlayBvidRequest, PlayRequest, PreviousRequest, QueueLastRequest,
+    QueueNextRequest, RemoveFromPlaylistRequest, RenamePlaylistRequest, ResumeRequest, SeekMode,
+    SeekRequest, SetModelRequest, SetVolumeRequest, ShowMusicPageInfoRequest, SortPlaylistRequest,
+    StopRequest, SubscribeRequest, SwapTracksRequest, SwitchPlaylistRequest,
+    UpdateCredentialsRequest, deleted_request::Target, player_event::Event,
     player_service_client::PlayerServiceClient,
 };
+use bili_player::player::{
+    model::MusicInfo, play_mode::PlayMode, playback::PlaybackState, reorder::SortKey,
+};
 use clap::{Parser, Subcommand};
+#[cfg(unix)]
+use hyper_util::rt::TokioIo;
+use std::{path::PathBuf, time::Duration};
+#[cfg(unix)]
+use tonic::transport::{Endpoint, Uri};
+use tonic::{service::interceptor::InterceptedService, transport::Channel};
 #[derive(Debug, Parser)]
 #[command(
     name = "bpc",
@@ -11,6 +30,26 @@ use clap::{Parser, Subcommand};
     version = "1.0.0"
 )]
 struct Cli {
+    #[arg(
+        long = "server",
+        global = true,
+        help = "播放器服务地址，例如 http://[::1]:50052 或 unix:/run/user/1000/bili-player/player.sock"
+    )]
+    server: Option<String>,
+    #[arg(
+        long = "config",
+        global = true,
+        help = "配置文件路径，默认为 $XDG_CONFIG_HOME/bili-player/config.toml"
+    )]
+    config: Option<PathBuf>,
+    #[arg(
+        long = "token",
+        global = true,
+        env = "BPC_TOKEN",
+        hide_env_values = true,
+        help = "访问令牌，默认使用配置文件中的 client.token"
+    )]
+    token: Option<String>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -33,7 +72,7 @@ enum Commands {
     Previous,
 
     #[command(about = "停止 Bilibili Player")]
-    Stop,
+    Stop(StopCommand),
 
     #[command(about = "设置播放模式")]
     Mode(ModeCommand),
@@ -55,6 +94,108 @@ enum Commands {
 
     #[command(about = "显示播放列表")]
     Page(PageCommand),
+
+    #[command(about = "跳转播放位置")]
+    Seek(SeekCommand),
+
+    #[command(about = "持续显示播放器事件")]
+    Watch,
+
+    #[command(about = "移动播放列表中的歌曲")]
+    Move(MoveCommand),
+
+    #[command(about = "交换播放列表中两首歌的位置")]
+    Swap(SwapCommand),
+
+    #[command(about = "对播放列表排序")]
+    Sort(SortCommand),
+
+    #[command(subcommand, about = "管理待播队列")]
+    Queue(QueueCommand),
+
+    #[command(subcommand, about = "管理歌单")]
+    Playlist(PlaylistCommand),
+
+    #[command(about = "扫码登录 B 站，登录后可以播放高音质和会员内容")]
+    Login(LoginArgs),
+}
+
+#[derive(Debug, Subcommand)]
+enum PlaylistCommand {
+    #[command(about = "创建歌单")]
+    Create {
+        #[arg(help = "歌单名称")]
+        name: String,
+    },
+    #[command(about = "重命名歌单")]
+    Rename {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(help = "新的歌单名称")]
+        new_name: String,
+    },
+    #[command(about = "删除歌单，歌曲仍保留在音乐库中")]
+    Delete {
+        #[arg(help = "歌单名称")]
+        name: String,
+    },
+    #[command(about = "添加歌曲到歌单")]
+    Add {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(help = "要添加的 bvid")]
+        bvid: String,
+    },
+    #[command(about = "从歌单中移除歌曲")]
+    Remove {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(help = "要移除的 bvid")]
+        bvid: String,
+    },
+    #[command(about = "切换歌单，不指定名称时切换回音乐库")]
+    Switch {
+        #[arg(help = "歌单名称")]
+        name: Option<String>,
+    },
+    #[command(about = "显示所有歌单")]
+    List,
+}
+
+#[derive(Debug, Parser)]
+struct LoginArgs {
+    #[command(subcommand)]
+    command: Option<LoginCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+enum LoginCommand {
+    #[command(about = "检查登录凭据是否有效")]
+    Status,
+}
+
+#[derive(Debug, Subcommand)]
+enum QueueCommand {
+    #[command(about = "当前歌曲结束后播放")]
+    Next {
+        #[arg(help = "要播放的 bvid")]
+        bvid: String,
+    },
+    #[command(about = "追加到待播队列末尾")]
+    Last {
+        #[arg(help = "要播放的 bvid")]
+        bvid: String,
+    },
+    #[command(about = "清空待播队列")]
+    Clear,
+    #[command(about = "显示待播队列")]
+    List,
+}
+
+#[derive(Debug, Parser)]
+struct StopCommand {
+    #[arg(short = 'q', long = "quit", help = "同时关闭播放器服务")]
+    quit: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -67,6 +208,13 @@ struct PageCommand {
 struct PlayCommand {
     #[arg(short = 'b', long = "bvid", help = "要播放的 bvid")]
     bvid: Option<String>,
+    #[arg(
+        short = 'i',
+        long = "index",
+        conflicts_with = "bvid",
+        help = "要播放的序号（即 bpc page 或 bpc find 中显示的序号）"
+    )]
+    index: Option<u32>,
 }
 
 #[derive(Debug, Parser)]
@@ -78,16 +226,27 @@ struct VolumeCommand {
 #[derive(Debug, Parser)]
 struct AddCommand {
     #[arg(short = 'b', long = "bvid", help = "要导入的 bvid")]
-    bvid: Option<String>,
+    bvid: String,
+    #[arg(short = 'n', long = "name", help = "歌曲名称，默认使用视频标题")]
+    name: Option<String>,
 }
 #[derive(Debug, Parser)]
+#[group(required = true, multiple = false)]
 struct DeleteCommand {
     #[arg(short = 'b', long = "bvid", help = "按 bvid 删除")]
-    bvid: String,
+    bvid: Option<String>,
+    #[arg(
+        short = 'i',
+        long = "index",
+        help = "按序号删除（即 bpc page 中显示的序号）"
+    )]
+    index: Option<u32>,
+    #[arg(short = 'r', long = "range", help = "按序号区间删除，例如 3-7")]
+    range: Option<String>,
 }
 #[derive(Debug, Parser)]
 struct ModeCommand {
-    #[arg(short = 'n', long = "normal", action = clap::ArgAction::SetTrue, help = "设置播放模式为循环播放")]
+    #[arg(short = 'n', long = "normal", action = clap::ArgAction::SetTrue, help = "设置播放模式为顺序播放")]
     normal_mode: bool,
     #[arg(short = 's', long = "shuffle", action = clap::ArgAction::SetTrue, help = "设置播放模式为随机播放")]
     shuffle_mode: bool,
@@ -95,6 +254,12 @@ struct ModeCommand {
     repeat_mode: bool,
     #[arg(short = 'a', long = "repeatall", action = clap::ArgAction::SetTrue, help = "设置播放模式为列表循环播放")]
     repeatall_mode: bool,
+    #[arg(
+        short = 'l',
+        long = "shuffle-loop",
+        help = "随机播放完一轮后是否重新洗牌继续（true/false）"
+    )]
+    shuffle_loop: Option<bool>,
 }
 #[derive(Debug, Parser)]
 struct FindCommand {
@@ -106,6 +271,277 @@ struct FindCommand {
     title: Option<String>,
     #[arg(short = 'o', long = "owner", help = "按作者查找")]
     owner: Option<String>,
+    #[arg(short = 'l', long = "limit", help = "最多显示多少条结果")]
+    limit: Option<u32>,
+}
+#[derive(Debug, Parser)]
+struct MoveCommand {
+    #[arg(help = "要移动的歌曲序号（即 bpc page 中显示的序号）")]
+    from: u32,
+    #[arg(required_unless_present = "top", help = "移动到的序号")]
+    to: Option<u32>,
+    #[arg(
+        short = 't',
+        long = "top",
+        conflicts_with = "to",
+        help = "移动到最前面"
+    )]
+    top: bool,
+}
+#[derive(Debug, Parser)]
+struct SwapCommand {
+    #[arg(help = "第一首歌的序号")]
+    first: u32,
+    #[arg(help = "第二首歌的序号")]
+    second: u32,
+}
+#[derive(Debug, Parser)]
+struct SortCommand {
+    #[arg(help = "排序依据：title、artist、owner、duration 或 added")]
+    key: String,
+    #[arg(short = 'd', long = "desc", help = "降序排列")]
+    descending: bool,
+}
+#[derive(Debug, Parser)]
+struct SeekCommand {
+    #[arg(
+        allow_hyphen_values = true,
+        help = "跳转位置：1:30 或 90 为绝对位置，+10/-10 为相对秒数，50% 为百分比"
+    )]
+    position: String,
+}
+/// 每个请求都会附带访问令牌的客户端
+type Client = PlayerServiceClient<InterceptedService<Channel, TokenInterceptor>>;
+/// 连接播放器服务，`unix:` 开头的地址通过 Unix socket 连接
+async fn connect(server: &str, token: TokenInterceptor) -> anyhow::Result<Client> {
+    let channel = match server.strip_prefix("unix:") {
+        Some(path) => connect_unix_socket(PathBuf::from(path)).await?,
+        None => Channel::from_shared(server.to_string())?.connect().await?,
+    };
+    Ok(PlayerServiceClient::with_interceptor(channel, token))
+}
+/// 没有指定地址时先尝试 Unix socket，连接失败再使用配置中的地址
+///
+/// socket 文件可能是服务异常退出后留下的，不能只根据文件是否存在来选择。
+async fn connect_default(config: &Config, token: TokenInterceptor) -> anyhow::Result<Client> {
+    let socket = config.server.socket_path();
+    if config.client.server.is_none() && socket.exists() {
+        let server = format!("unix:{}", socket.display());
+        match connect(&server, token.clone()).await {
+            Ok(client) => return Ok(client),
+            Err(e) => eprintln!("无法连接 {}，改用 {}: {}", server, config.server_url(), e),
+        }
+    }
+    connect(&config.server_url(), token).await
+}
+#[cfg(unix)]
+async fn connect_unix_socket(path: PathBuf) -> anyhow::Result<Channel> {
+    // 通过 Unix socket 连接时不会用到这个地址，只是 Endpoint 需要一个合法的 URI
+    // 不连接其他用户创建的 socket，避免把访问令牌发给冒充的服务
+    bili_player::config::ServerConfig::check_socket_dir(&path)?;
+    let channel = Endpoint::from_static("http://[::1]:50052")
+        .connect_with_connector(tower::service_fn(move |_: Uri| {
+            let path = path.clone();
+            async move {
+                let stream = tokio::net::UnixStream::connect(path).await?;
+                Ok::<_, std::io::Error>(TokioIo::new(stream))
+            }
+        }))
+        .await?;
+    Ok(channel)
+}
+#[cfg(not(unix))]
+async fn connect_unix_socket(_path: PathBuf) -> anyhow::Result<Channel> {
+    anyhow::bail!("当前平台不支持 Unix socket")
+}
+/// 解析 `3-7` 格式的序号区间
+fn parse_index_range(input: &str) -> anyhow::Result<IndexRange> {
+    let (start, end) = input
+        .split_once('-')
+        .ok_or_else(|| anyhow::anyhow!("序号区间格式应为 start-end: {}", input))?;
+    Ok(IndexRange {
+        start: start.trim().parse()?,
+        end: end.trim().parse()?,
+    })
+}
+/// 解析 `mm:ss`、`h:mm:ss` 或纯秒数，返回毫秒
+///
+/// 每一段都必须是非负整数，只有最后一段可以带小数，例如 `1:30.5`。
+fn parse_time_ms(input: &str) -> anyhow::Result<i64> {
+    let invalid = || anyhow::anyhow!("无法解析时间: {}", input);
+    let parts: Vec<&str> = input.split(':').map(str::trim).collect();
+    if parts.len() > 3 {
+        return Err(invalid());
+    }
+    let (last, fields) = parts.split_last().ok_or_else(invalid)?;
+    let (whole, fraction) = match last.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (*last, None),
+    };
+    let mut seconds: i64 = 0;
+    for part in fields.iter().chain([&whole]) {
+        let value = parse_digits(part).ok_or_else(invalid)?;
+        seconds = seconds
+            .checked_mul(60)
+            .and_then(|seconds| seconds.checked_add(value))
+            .ok_or_else(invalid)?;
+    }
+    // 小数部分精确到毫秒
+    let millis = match fraction {
+        Some(fraction) => {
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            format!("{:0<3}", &fraction[..fraction.len().min(3)]).parse()?
+        }
+        None => 0,
+    };
+    seconds
+        .checked_mul(1000)
+        .and_then(|ms| ms.checked_add(millis))
+        .ok_or_else(invalid)
+}
+/// 解析只由数字组成的非负整数，不接受符号、小数和指数
+fn parse_digits(input: &str) -> Option<i64> {
+    if input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    input.parse().ok()
+}
+/// 解析跳转位置参数
+fn parse_seek_position(input: &str) -> anyhow::Result<SeekRequest> {
+    let input = input.trim();
+    let (position, mode) = if let Some(percent) = input.strip_suffix('%') {
+        let percent: i64 = percent
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("无法解析百分比: {}", input))?;
+        (percent, SeekMode::Percent)
+    } else if let Some(offset) = input.strip_prefix('+') {
+        (parse_time_ms(offset)?, SeekMode::Relative)
+    } else if let Some(offset) = input.strip_prefix('-') {
+        (-parse_time_ms(offset)?, SeekMode::Relative)
+    } else {
+        (parse_time_ms(input)?, SeekMode::Absolute)
+    };
+    Ok(SeekRequest {
+        position,
+        mode: mode as i32,
+    })
+}
+/// 把毫秒格式化为 mm:ss，未知时显示 Unknown
+fn format_ms(ms: Option<u64>) -> String {
+    match ms {
+        Some(ms) => {
+            let secs = ms / 1000;
+            format!("{:02}:{:02}", secs / 60, secs % 60)
+        }
+        None => "Unknown".to_string(),
+    }
+}
+/// 把播放器状态渲染为便于阅读的文本
+fn format_state(state: &GetStateResponse) -> String {
+    let music: Option<MusicInfo> = state.music.clone().map(Into::into);
+    let title = music
+        .as_ref()
+        .map(|music| music.title.as_str())
+        .unwrap_or("None");
+    let duration = format_ms(state.duration_ms);
+    let mut output = format!(
+        "{}:《{}》时长:{}/{}, 音量:{}, 播放模式:{}, 第{}个/共{}个。",
+        PlaybackState::from(state.state()).show_info(),
+        title,
+        format_ms(state.position_ms),
+        duration,
+        state.volume,
+        PlayMode::from(state.mode()).get_string(),
+        state.index.unwrap_or_default(),
+        state.playlist_length,
+    );
+    if let Some(quality) = state
+        .audio_quality
+        .and_then(|q| pb::AudioQuality::try_from(q).ok())
+        && let Some(quality) = Option::<AudioQuality>::from(quality)
+    {
+        output.push_str(&format!(" 音质:{}", quality.get_string()));
+    }
+    if let Some(music) = music {
+        output.push_str(&format!("\n当前播放:{}", music));
+        // 与 musics.txt 相同的格式，便于复制保存
+        output.push_str(&format!(
+            "\n保存：《{}》-{}-{}-{}-{}-{}",
+            music.title,
+            duration,
+            music.bvid,
+            music.cid,
+            music.artist.as_deref().unwrap_or("Unknown"),
+            music.owner
+        ));
+    }
+    output
+}
+/// 把播放器事件渲染为一行文本
+fn format_event(event: Event) -> String {
+    match event {
+        Event::TrackChanged(track) => {
+            let music = track
+                .music
+                .map(|music| MusicInfo::from(music).to_string())
+                .unwrap_or_default();
+            format!("[歌曲] 第{}个: {}", track.index.unwrap_or_default(), music)
+        }
+        Event::StateChanged(state) => {
+            let state = bili_player::pb::PlaybackState::try_from(state).unwrap_or_default();
+            format!("[状态] {}", PlaybackState::from(state).show_info())
+        }
+        Event::Position(tick) => format!(
+            "[进度] {}/{}",
+            format_ms(tick.position_ms),
+            format_ms(tick.duration_ms)
+        ),
+        Event::VolumeChanged(volume) => format!("[音量] {}", volume),
+        Event::ModeChanged(mode) => {
+            let mode = bili_player::pb::PlayMode::try_from(mode).unwrap_or_default();
+            format!("[模式] {}", PlayMode::from(mode).get_string())
+        }
+        Event::PlaylistChanged(playlist) => format!("[列表] 共{}个", playlist.length),
+        Event::Error(error) => format!("[错误] {}", error),
+    }
+}
+/// 轮询扫码结果的间隔
+const QR_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// 在终端显示登录二维码，扫码确认后把凭据交给服务端保存
+async fn qr_login(client: &mut Client) -> anyhow::Result<()> {
+    let passport = Passport::new(reqwest::Client::new());
+    let login = passport.generate_qrcode().await?;
+    println!("{}", render_qrcode(&login.url)?);
+    eprintln!("请使用哔哩哔哩 App 扫描二维码");
+    let mut scanned = false;
+    loop {
+        tokio::time::sleep(QR_POLL_INTERVAL).await;
+        match passport.poll(&login.qrcode_key).await? {
+            QrLoginStatus::Waiting => {}
+            QrLoginStatus::Scanned => {
+                if !scanned {
+                    scanned = true;
+                    eprintln!("已扫码，请在手机上确认登录");
+                }
+            }
+            QrLoginStatus::Expired => anyhow::bail!("二维码已失效，请重新运行 bpc login"),
+            QrLoginStatus::Confirmed(credentials) => {
+                let request = tonic::Request::new(UpdateCredentialsRequest {
+                    sessdata: credentials.sessdata,
+                    bili_jct: credentials.bili_jct,
+                    buvid3: credentials.buvid3,
+                });
+                let response = client.update_credentials(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+                return Ok(());
+            }
+        }
+    }
 }
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -113,7 +549,15 @@ async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
     // eprintln!("Cli:{:?}", cli);
     // 创建连接
-    let mut client = PlayerServiceClient::connect("http://[::1]:50052").await?;
+    let config = Config::load(cli.config.as_deref())?;
+    // 命令行或环境变量 BPC_TOKEN 中的令牌优先
+    let token = cli.token.or(config.client.token.clone());
+    let token = TokenInterceptor::new(token.as_deref())?;
+    // 命令行指定的地址优先，其次是配置文件中的地址，都没有时优先使用 Unix socket
+    let mut client = match cli.server {
+        Some(server) => connect(&server, token).await?,
+        None => connect_default(&config, token).await?,
+    };
     match cli.command {
         // 播放，如果有传入 bvid，则播放 bvid 的歌曲，否则播放当前歌曲
         Commands::Play(play_cmd) => {
@@ -124,7 +568,9 @@ async fn main() -> anyhow::Result<()> {
                     eprintln!("{}", response.message);
                 };
             } else {
-                let request = tonic::Request::new(PlayRequest {});
+                let request = tonic::Request::new(PlayRequest {
+                    index: play_cmd.index.unwrap_or_default(),
+                });
                 let response = client.play(request).await?.into_inner();
                 if response.success {
                     eprintln!("{}", response.message);
@@ -164,8 +610,10 @@ async fn main() -> anyhow::Result<()> {
             };
         }
         // 停止播放
-        Commands::Stop => {
-            let request = tonic::Request::new(StopRequest {});
+        Commands::Stop(stop_cmd) => {
+            let request = tonic::Request::new(StopRequest {
+                quit: stop_cmd.quit,
+            });
             let response = client.stop(request).await?.into_inner();
             if response.success {
                 eprintln!("{}", response.message);
@@ -177,11 +625,14 @@ async fn main() -> anyhow::Result<()> {
             } else if mode_cmd.repeat_mode {
                 "repeat".into() // 单曲循环播放
             } else if mode_cmd.repeatall_mode {
-                "repeatall".into() // 列表循环播放
+                "repeat_all".into() // 列表循环播放
             } else {
                 "normal".into() // 列表播放
             };
-            let request = tonic::Request::new(SetModelRequest { model });
+            let request = tonic::Request::new(SetModelRequest {
+                model,
+                shuffle_loop: mode_cmd.shuffle_loop,
+            });
             let response = client.set_model(request).await?.into_inner();
             if response.success {
                 eprintln!("{}", response.message);
@@ -196,16 +647,60 @@ async fn main() -> anyhow::Result<()> {
                 eprintln!("{}", response.message);
             };
         }
-        Commands::Add(_add_cmd) => {}
-        Commands::Delete(_delete_cmd) => {}
+        Commands::Add(add_cmd) => {
+            let request = tonic::Request::new(AddPlaylistRequest {
+                bvid: add_cmd.bvid,
+                song_name: add_cmd.name.unwrap_or_default(),
+            });
+            let response = client.add_playlist(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
+        Commands::Delete(delete_cmd) => {
+            let target = if let Some(bvid) = delete_cmd.bvid {
+                Target::Bvid(bvid)
+            } else if let Some(index) = delete_cmd.index {
+                Target::Index(index)
+            } else if let Some(range) = delete_cmd.range {
+                Target::Range(parse_index_range(&range)?)
+            } else {
+                unreachable!("clap 保证至少指定一种删除方式")
+            };
+            let request = tonic::Request::new(DeletedRequest {
+                target: Some(target),
+            });
+            let response = client.deleted(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
         Commands::State => {
             let request = tonic::Request::new(GetStateRequest {});
             let response = client.get_state(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", format_state(&response));
+            };
+        }
+        Commands::Find(find_cmd) => {
+            let request = tonic::Request::new(FindRequest {
+                bvid: find_cmd.bvid.unwrap_or_default(),
+                cid: find_cmd.cid.unwrap_or_default(),
+                title: find_cmd.title.unwrap_or_default(),
+                owner: find_cmd.owner.unwrap_or_default(),
+                limit: find_cmd.limit.unwrap_or_default(),
+            });
+            let response = client.find(request).await?.into_inner();
             if response.success {
                 eprintln!("{}", response.message);
+                for found in response.results {
+                    if let Some(music) = found.music {
+                        let music: MusicInfo = music.into();
+                        eprintln!("[{}] {}", found.index, music);
+                    }
+                }
             };
         }
-        Commands::Find(_find_cmd) => {}
         Commands::Page(page_cmd) => {
             let request = tonic::Request::new(ShowMusicPageInfoRequest {
                 page: page_cmd.page,
@@ -219,6 +714,168 @@ async fn main() -> anyhow::Result<()> {
                 );
             };
         }
+        Commands::Seek(seek_cmd) => {
+            let request = tonic::Request::new(parse_seek_position(&seek_cmd.position)?);
+            let response = client.seek(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
+        Commands::Move(move_cmd) => {
+            let request = tonic::Request::new(MoveTrackRequest {
+                from: move_cmd.from,
+                to: if move_cmd.top {
+                    1
+                } else {
+                    move_cmd.to.unwrap_or(1)
+                },
+            });
+            let response = client.move_track(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
+        Commands::Swap(swap_cmd) => {
+            let request = tonic::Request::new(SwapTracksRequest {
+                first: swap_cmd.first,
+                second: swap_cmd.second,
+            });
+            let response = client.swap_tracks(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
+        Commands::Sort(sort_cmd) => {
+            let key = SortKey::from_string(&sort_cmd.key)
+                .ok_or_else(|| anyhow::anyhow!("未知的排序依据: {}", sort_cmd.key))?;
+            let request = tonic::Request::new(SortPlaylistRequest {
+                key: bili_player::pb::SortKey::from(key) as i32,
+                descending: sort_cmd.descending,
+            });
+            let response = client.sort_playlist(request).await?.into_inner();
+            if response.success {
+                eprintln!("{}", response.message);
+            };
+        }
+        Commands::Queue(queue_cmd) => match queue_cmd {
+            QueueCommand::Next { bvid } => {
+                let request = tonic::Request::new(QueueNextRequest { bvid });
+                let response = client.queue_next(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            QueueCommand::Last { bvid } => {
+                let request = tonic::Request::new(QueueLastRequest { bvid });
+                let response = client.queue_last(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            QueueCommand::Clear => {
+                let request = tonic::Request::new(ClearQueueRequest {});
+                let response = client.clear_queue(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            QueueCommand::List => {
+                let request = tonic::Request::new(ListQueueRequest {});
+                let response = client.list_queue(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                    for (i, music) in response.musics.into_iter().enumerate() {
+                        eprintln!("[{}] {}", i + 1, MusicInfo::from(music));
+                    }
+                };
+            }
+        },
+        Commands::Login(login_args) => match login_args.command {
+            // 没有子命令时扫码登录
+            None => qr_login(&mut client).await?,
+            Some(LoginCommand::Status) => {
+                let request = tonic::Request::new(LoginStatusRequest {});
+                let response = client.login_status(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+        },
+        Commands::Playlist(playlist_cmd) => match playlist_cmd {
+            PlaylistCommand::Create { name } => {
+                let request = tonic::Request::new(CreatePlaylistRequest { name });
+                let response = client.create_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            PlaylistCommand::Rename { name, new_name } => {
+                let request = tonic::Request::new(RenamePlaylistRequest { name, new_name });
+                let response = client.rename_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            PlaylistCommand::Delete { name } => {
+                let request = tonic::Request::new(DeletePlaylistRequest { name });
+                let response = client.delete_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            PlaylistCommand::Add { name, bvid } => {
+                let request = tonic::Request::new(AddToPlaylistRequest { name, bvid });
+                let response = client.add_to_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            PlaylistCommand::Remove { name, bvid } => {
+                let request = tonic::Request::new(RemoveFromPlaylistRequest { name, bvid });
+                let response = client.remove_from_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                };
+            }
+            PlaylistCommand::Switch { name } => {
+                let request = tonic::Request::new(SwitchPlaylistRequest {
+                    name: name.unwrap_or_default(),
+                });
+                let response = client.switch_playlist(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                    if let (Some(index), Some(music)) = (response.index, response.music) {
+                        eprintln!("当前歌曲: [{}] {}", index, MusicInfo::from(music));
+                    }
+                };
+            }
+            PlaylistCommand::List => {
+                let request = tonic::Request::new(ListPlaylistsRequest {});
+                let response = client.list_playlists(request).await?.into_inner();
+                if response.success {
+                    eprintln!("{}", response.message);
+                    for playlist in response.playlists {
+                        let name = if playlist.is_library {
+                            "音乐库".to_string()
+                        } else {
+                            playlist.name
+                        };
+                        let active = if playlist.active { "*" } else { " " };
+                        eprintln!("{} {} ({}首)", active, name, playlist.track_count);
+                    }
+                };
+            }
+        },
+        Commands::Watch => {
+            let request = tonic::Request::new(SubscribeRequest {});
+            let mut events = client.subscribe(request).await?.into_inner();
+            // 持续接收事件，直到服务端关闭
+            while let Some(event) = events.message().await? {
+                if let Some(event) = event.event {
+                    eprintln!("{}", format_event(event));
+                }
+            }
+        }
     }
     Ok(())
 }
@@ -0,0 +1,251 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    auth::TokenConfig,
+    errors::{PlayerError, PlayerResult},
+    fetch::credentials::Credentials,
+    library::DEFAULT_DATABASE_URL,
+};
+
+/// 配置、缓存等目录下使用的应用目录名
+pub const APP_DIR: &str = "bili-player";
+/// 配置文件名
+pub const CONFIG_FILE: &str = "config.toml";
+/// 默认的登录 Cookie 文件名，扫码登录后写入配置目录
+pub const COOKIE_FILE: &str = "cookies.txt";
+/// 默认的 gRPC 监听地址
+pub const DEFAULT_LISTEN: &str = "[::1]:50052";
+/// Unix socket 文件名
+pub const SOCKET_FILE: &str = "player.sock";
+
+/// 配置文件，server 和 bpc 共用
+///
+/// 默认位于 `$XDG_CONFIG_HOME/bili-player/config.toml`，文件不存在或缺少的字段使用默认值：
+///
+/// ```toml
+/// [server]
+/// listen = "[::1]:50052"
+/// unix_socket = false
+/// socket_path = "/run/user/1000/bili-player/player.sock"
+/// library = "musics.txt"
+/// database_url = "sqlite:musics_data.db"
+/// volume = 10
+/// play_mode = "normal"
+/// # max、data_saver 或 192k 形式的码率上限，max 会选择需要额外解码插件的杜比和 Hi-Res 音轨
+/// audio_quality = "192k"
+/// # 音频地址都不可用时依次替换成这些 CDN 域名重试
+/// cdn_hosts = ["upos-sz-mirrorcos.bilivideo.com", "upos-sz-mirrorali.bilivideo.com"]
+/// cache_dir = "/home/user/.cache/bili-player"
+/// # 浏览器导出的 cookies.txt，或者一行 `SESSDATA=...; bili_jct=...`
+/// cookie_file = "/home/user/Downloads/cookies.txt"
+/// # 扫码登录后把凭据写回 cookie_file，默认写入配置目录下的 cookies.txt
+/// overwrite_cookie_file = false
+///
+/// [server.log]
+/// level = "info"
+/// file = false
+///
+/// # 登录凭据，优先于 cookie_file 中的同名 Cookie
+/// [server.credentials]
+/// sessdata = "..."
+/// bili_jct = "..."
+/// buvid3 = "..."
+///
+/// # 配置了令牌后所有请求都需要认证，scope 为 read 的令牌只能查询
+/// [[server.tokens]]
+/// token = "change-me"
+/// scope = "full"
+/// name = "laptop"
+///
+/// [client]
+/// server = "http://[::1]:50052"
+/// token = "change-me"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub client: ClientConfig,
+}
+
+/// 服务端配置
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    pub listen: String,               // gRPC 监听地址
+    pub unix_socket: bool,            // 改为监听 Unix socket，只有当前用户可以访问
+    pub socket_path: Option<PathBuf>, // Unix socket 路径，默认为 $XDG_RUNTIME_DIR/bili-player/player.sock
+    pub library: Option<PathBuf>,     // 音乐库为空时导入的歌曲列表文件，例如 musics.txt
+    pub database_url: String,         // 数据库地址
+    pub volume: u32,                  // 默认音量百分比
+    pub play_mode: String,            // 默认播放模式
+    pub audio_quality: String,        // 音质偏好
+    pub cdn_hosts: Vec<String>,       // 备选的音频 CDN 域名
+    pub cache_dir: PathBuf,           // 缓存目录，日志文件也写在这里
+    pub log: LogConfig,               // 日志设置
+    pub tokens: Vec<TokenConfig>,     // 访问令牌，为空时不需要认证
+    pub cookie_file: Option<PathBuf>, // 登录 Cookie 文件，默认为配置目录下的 cookies.txt
+    pub overwrite_cookie_file: bool,  // 扫码登录后把凭据写回 cookie_file
+    pub credentials: Credentials,     // 登录凭据
+}
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            listen: DEFAULT_LISTEN.to_string(),
+            unix_socket: false,
+            socket_path: None,
+            library: None,
+            database_url: DEFAULT_DATABASE_URL.to_string(),
+            volume: 10,
+            play_mode: "normal".to_string(),
+            audio_quality: "192k".to_string(),
+            cdn_hosts: Vec::new(),
+            cache_dir: dirs::cache_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join(APP_DIR),
+            log: LogConfig::default(),
+            tokens: Vec::new(),
+            cookie_file: None,
+            overwrite_cookie_file: false,
+            credentials: Credentials::default(),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Unix socket 路径，没有配置时放在 $XDG_RUNTIME_DIR 下
+    pub fn socket_path(&self) -> PathBuf {
+        self.socket_path.clone().unwrap_or_else(|| {
+            dirs::runtime_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join(APP_DIR)
+                .join(SOCKET_FILE)
+        })
+    }
+    /// 检查 Unix socket 所在的目录只有当前用户可以访问
+    ///
+    /// 没有 $XDG_RUNTIME_DIR 时 socket 放在临时目录下，目录可能被其他用户抢先创建，
+    /// 不属于当前用户或者组和其他用户有权限时拒绝使用。
+    #[cfg(unix)]
+    pub fn check_socket_dir(path: &Path) -> PlayerResult<()> {
+        use std::os::unix::fs::MetadataExt;
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let metadata = std::fs::metadata(dir)?;
+        // SAFETY: geteuid 没有参数，总是成功
+        let uid = unsafe { libc::geteuid() };
+        if metadata.uid() != uid || metadata.mode() & 0o077 != 0 {
+            return Err(PlayerError::Config(format!(
+                "Unix socket 目录 {} 必须属于当前用户且权限为 0700",
+                dir.display()
+            )));
+        }
+        Ok(())
+    }
+    /// 配置目录下默认的登录 Cookie 文件
+    pub fn default_cookie_file() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(std::env::temp_dir)
+            .join(APP_DIR)
+            .join(COOKIE_FILE)
+    }
+    /// 登录 Cookie 文件路径，没有配置时放在配置目录下
+    pub fn cookie_file_path(&self) -> PathBuf {
+        self.cookie_file
+            .clone()
+            .unwrap_or_else(Self::default_cookie_file)
+    }
+    /// 扫码登录后写入凭据的文件
+    ///
+    /// 只有设置了 overwrite_cookie_file 时才写回 cookie_file，否则写入默认路径，
+    /// 不会覆盖浏览器导出的 cookies.txt。
+    pub fn login_cookie_file(&self) -> PathBuf {
+        if self.overwrite_cookie_file {
+            self.cookie_file_path()
+        } else {
+            Self::default_cookie_file()
+        }
+    }
+    /// 登录凭据，依次读取 Cookie 文件和扫码登录保存的文件，再用配置文件中的凭据覆盖
+    ///
+    /// 指定了 cookie_file 时文件必须存在，默认路径的文件不存在时视为没有登录。
+    pub fn load_credentials(&self) -> PlayerResult<Credentials> {
+        let read = |path: &Path| {
+            Credentials::from_cookie_file(path)
+                .map_err(|e| PlayerError::Config(format!("{}: {}", path.display(), e)))
+        };
+        let path = self.cookie_file_path();
+        let mut credentials = if self.cookie_file.is_some() || path.exists() {
+            read(&path)?
+        } else {
+            Credentials::default()
+        };
+        // 扫码登录保存的凭据比导出的 Cookie 文件更新
+        let login_path = self.login_cookie_file();
+        if login_path != path && login_path.exists() {
+            credentials = credentials.merge(read(&login_path)?);
+        }
+        Ok(credentials.merge(self.credentials.clone()))
+    }
+}
+
+/// 日志设置
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LogConfig {
+    pub level: String, // 日志等级
+    pub file: bool,    // 是否同时写入缓存目录下按天滚动的日志文件
+}
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            file: false,
+        }
+    }
+}
+
+/// 客户端配置
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ClientConfig {
+    pub server: Option<String>, // 连接的服务地址，不设置时优先使用 Unix socket，否则根据 server.listen 推导
+    pub token: Option<String>,  // 访问令牌，环境变量 BPC_TOKEN 优先
+}
+
+impl Config {
+    /// 默认的配置文件路径
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR).join(CONFIG_FILE))
+    }
+    /// 读取配置文件
+    ///
+    /// 指定了 path 时文件必须存在；没有指定时读取默认路径，文件不存在则使用默认配置。
+    pub fn load(path: Option<&Path>) -> PlayerResult<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match Self::default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+        let content = std::fs::read_to_string(&path)?;
+        Self::from_toml(&content)
+            .map_err(|e| PlayerError::Config(format!("{}: {}", path.display(), e)))
+    }
+    /// 从 TOML 文本解析配置
+    pub fn from_toml(content: &str) -> PlayerResult<Self> {
+        toml::from_str(content).map_err(|e| PlayerError::Config(e.to_string()))
+    }
+    /// 配置的服务地址，`unix:` 开头时表示 Unix socket 路径；没有配置时使用 server.listen
+    pub fn server_url(&self) -> String {
+        match &self.client.server {
+            Some(server) => server.clone(),
+            None => format!("http://{}", self.server.listen),
+        }
+    }
+}
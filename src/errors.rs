@@ -32,6 +32,30 @@ pub enum PlayerError {
 
     #[error("Pipeline error: {0}")]
     Pipeline(String),
+
+    #[error("Seek position out of range: {0}")]
+    SeekOutOfRange(String),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Database migration error: {0}")]
+    Migrate(#[from] sqlx::migrate::MigrateError),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Already exists: {0}")]
+    AlreadyExists(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Config error: {0}")]
+    Config(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
 }
 
 impl From<gstreamer::glib::BoolError> for PlayerError {
@@ -46,4 +70,22 @@ impl From<gstreamer::glib::Error> for PlayerError {
     }
 }
 
+/// 把播放器错误转换为 gRPC 状态码
+impl From<PlayerError> for tonic::Status {
+    fn from(err: PlayerError) -> Self {
+        match err {
+            PlayerError::SeekOutOfRange(_) | PlayerError::InvalidIndex(_) => {
+                tonic::Status::out_of_range(err.to_string())
+            }
+            PlayerError::VolumeRange(_) | PlayerError::InvalidArgument(_) => {
+                tonic::Status::invalid_argument(err.to_string())
+            }
+            PlayerError::AlreadyExists(_) => tonic::Status::already_exists(err.to_string()),
+            PlayerError::NotFound(_) => tonic::Status::not_found(err.to_string()),
+            PlayerError::StateTransition(_) => tonic::Status::failed_precondition(err.to_string()),
+            _ => tonic::Status::internal(err.to_string()),
+        }
+    }
+}
+
 pub type PlayerResult<T> = anyhow::Result<T, PlayerError>;
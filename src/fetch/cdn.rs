@@ -0,0 +1,96 @@
+use std::sync::Mutex;
+
+use reqwest::{Client, Url};
+
+use crate::fetch::{stream::AudioStream, verify::verify_audio_url};
+
+/// 音频 CDN 地址的候选顺序
+///
+/// 依次尝试音轨的主地址、备用地址和替换为备选域名后的主地址，并记住上次可用的域名，
+/// 之后的歌曲优先使用这个域名。
+#[derive(Debug, Default)]
+pub struct CdnHosts {
+    rewrite: Vec<String>,           // 备选域名，可以带端口
+    working: Mutex<Option<String>>, // 上次验证可用的域名
+}
+impl CdnHosts {
+    /// CdnHosts 构造函数
+    pub fn new(rewrite: Vec<String>) -> Self {
+        Self {
+            rewrite,
+            working: Mutex::new(None),
+        }
+    }
+    /// 上次验证可用的域名
+    pub fn working_host(&self) -> Option<String> {
+        self.working.lock().unwrap().clone()
+    }
+    /// 记录验证可用的地址所在的域名
+    pub fn record_working(&self, url: &str) {
+        let host = url_host(url);
+        let mut working = self.working.lock().unwrap();
+        if host.is_some() && *working != host {
+            tracing::info!(
+                "Audio CDN switched to {}",
+                host.as_deref().unwrap_or_default()
+            );
+            *working = host;
+        }
+    }
+    /// 按尝试顺序排列的候选地址，上次可用的域名排在最前面
+    pub fn candidates(&self, stream: &AudioStream) -> Vec<String> {
+        let mut urls: Vec<String> = std::iter::once(&stream.base_url)
+            .chain(&stream.backup_urls)
+            .cloned()
+            .collect();
+        urls.extend(
+            self.rewrite
+                .iter()
+                .filter_map(|host| replace_host(&stream.base_url, host)),
+        );
+        let mut seen = std::collections::HashSet::new();
+        urls.retain(|url| seen.insert(url.clone()));
+        if let Some(working) = self.working_host() {
+            // 稳定排序，其余地址保持原来的顺序
+            urls.sort_by_key(|url| url_host(url).as_deref() != Some(working.as_str()));
+        }
+        urls
+    }
+    /// 依次验证候选地址，返回第一个可用的地址，全部不可用时返回 None
+    pub async fn find_working_url(&self, client: &Client, stream: &AudioStream) -> Option<String> {
+        for url in self.candidates(stream) {
+            match verify_audio_url(client, &url).await {
+                Ok(true) => {
+                    self.record_working(&url);
+                    return Some(url);
+                }
+                Ok(false) => {
+                    tracing::info!("Verification failed for URL: {}", url);
+                }
+                Err(e) => {
+                    tracing::warn!("Error verifying URL {}: {}", url, e);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// 地址中的域名，有端口时带上端口
+pub fn url_host(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    })
+}
+
+/// 把地址中的域名替换为 host，host 可以带端口
+pub fn replace_host(url: &str, host: &str) -> Option<String> {
+    let mut url = Url::parse(url).ok()?;
+    let target = Url::parse(&format!("{}://{}", url.scheme(), host)).ok()?;
+    url.set_host(target.host_str()).ok()?;
+    url.set_port(target.port()).ok()?;
+    Some(url.to_string())
+}
@@ -0,0 +1,239 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+use reqwest::{
+    Client, Url,
+    cookie::{CookieStore, Jar},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    errors::{PlayerError, PlayerResult},
+    fetch::wbi::{NAV_API_URL, USER_AGENT},
+};
+
+/// 登录 Cookie 所属的站点
+const BILIBILI_URL: &str = "https://www.bilibili.com";
+
+/// B 站登录凭据，对应同名 Cookie
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Credentials {
+    pub sessdata: Option<String>, // SESSDATA，登录状态
+    pub bili_jct: Option<String>, // bili_jct，即 CSRF token
+    pub buvid3: Option<String>,   // buvid3，设备标识
+}
+impl Credentials {
+    /// 是否有登录凭据，凭据是否有效需要请求 nav 接口确认
+    pub fn has_session(&self) -> bool {
+        self.sessdata.is_some()
+    }
+    /// 用 other 中已设置的字段覆盖当前凭据
+    pub fn merge(mut self, other: Credentials) -> Self {
+        self.sessdata = other.sessdata.or(self.sessdata);
+        self.bili_jct = other.bili_jct.or(self.bili_jct);
+        self.buvid3 = other.buvid3.or(self.buvid3);
+        self
+    }
+    /// 按名称设置 Cookie，忽略无关的 Cookie
+    fn set(&mut self, name: &str, value: &str) {
+        let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        match name.trim() {
+            "SESSDATA" => self.sessdata = value,
+            "bili_jct" => self.bili_jct = value,
+            "buvid3" => self.buvid3 = value,
+            _ => {}
+        }
+    }
+    /// 解析 Cookie 文件内容
+    ///
+    /// 支持浏览器扩展导出的 Netscape cookies.txt 格式，以及 `SESSDATA=...; bili_jct=...` 形式的请求头。
+    pub fn parse_cookies(content: &str) -> Self {
+        let mut credentials = Self::default();
+        for line in content.lines().map(str::trim) {
+            // curl 用 #HttpOnly_ 前缀标记 HttpOnly 的 Cookie
+            let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() == 7 {
+                if fields[0].ends_with("bilibili.com") {
+                    credentials.set(fields[5], fields[6]);
+                }
+                continue;
+            }
+            let line = line.strip_prefix("Cookie:").unwrap_or(line);
+            for pair in line.split(';') {
+                if let Some((name, value)) = pair.split_once('=') {
+                    credentials.set(name, value);
+                }
+            }
+        }
+        credentials
+    }
+    /// 读取 Cookie 文件
+    pub fn from_cookie_file(path: &Path) -> PlayerResult<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(Self::parse_cookies(&content))
+    }
+    /// 转换为 `SESSDATA=...; bili_jct=...` 形式的请求头，没有凭据时返回空字符串
+    pub fn to_cookie_header(&self) -> String {
+        self.pairs()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+    /// 以请求头形式写入 Cookie 文件，文件只有当前用户可以读写
+    pub fn save_cookie_file(&self, path: &Path) -> PlayerResult<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        // 创建时就设置权限，写入凭据前文件不会被其他用户读到
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        // 文件已经存在时 mode 不生效，写入前收紧原有的权限
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(format!("{}\n", self.to_cookie_header()).as_bytes())?;
+        Ok(())
+    }
+    /// 已设置的 Cookie 名称和值
+    fn pairs(&self) -> impl Iterator<Item = (&'static str, &String)> {
+        [
+            ("SESSDATA", &self.sessdata),
+            ("bili_jct", &self.bili_jct),
+            ("buvid3", &self.buvid3),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.as_ref().map(|value| (name, value)))
+    }
+    /// 转换为 Set-Cookie 格式，作用于所有 bilibili.com 子域名
+    fn set_cookie_strings(&self) -> Vec<String> {
+        self.pairs()
+            .map(|(name, value)| format!("{}={}; Domain=.bilibili.com; Path=/", name, value))
+            .collect()
+    }
+}
+
+/// 共享的凭据存储
+///
+/// 内部的 Cookie jar 同时用于 reqwest 客户端和 GStreamer 请求音频时的 Cookie 请求头，
+/// 登录 Cookie 只会发送给 bilibili.com 及其子域名。
+#[derive(Debug, Clone)]
+pub struct CredentialStore {
+    jar: Arc<Jar>,
+    credentials: Arc<RwLock<Credentials>>,
+    file: Option<PathBuf>, // 更新凭据后写入的 Cookie 文件
+}
+impl Default for CredentialStore {
+    fn default() -> Self {
+        Self::new(Credentials::default())
+    }
+}
+impl CredentialStore {
+    /// CredentialStore 构造函数
+    pub fn new(credentials: Credentials) -> Self {
+        let store = Self {
+            jar: Arc::new(Jar::default()),
+            credentials: Arc::new(RwLock::new(Credentials::default())),
+            file: None,
+        };
+        store.set(credentials);
+        store
+    }
+    /// 更新凭据后写入 Cookie 文件，重启后仍然保持登录
+    pub fn persist_to(mut self, path: PathBuf) -> Self {
+        self.file = Some(path);
+        self
+    }
+    /// 更新凭据，没有设置的字段保持不变，设置了 Cookie 文件时同时写入
+    pub fn update(&self, credentials: Credentials) -> PlayerResult<()> {
+        let merged = self.set(credentials);
+        if let Some(path) = &self.file {
+            merged.save_cookie_file(path)?;
+        }
+        Ok(())
+    }
+    /// 把凭据写入 Cookie jar，返回合并后的凭据
+    fn set(&self, credentials: Credentials) -> Credentials {
+        let url = Url::parse(BILIBILI_URL).unwrap();
+        for cookie in credentials.set_cookie_strings() {
+            self.jar.add_cookie_str(&cookie, &url);
+        }
+        let mut current = self.credentials.write().unwrap();
+        *current = current.clone().merge(credentials);
+        current.clone()
+    }
+    /// 当前的凭据
+    pub fn credentials(&self) -> Credentials {
+        self.credentials.read().unwrap().clone()
+    }
+    /// 请求 url 时携带的 Cookie 请求头
+    ///
+    /// 按 Cookie 的域名匹配，CDN 等其他站点的地址和没有凭据时返回 None。
+    pub fn cookie_header(&self, url: &str) -> Option<String> {
+        let url = Url::parse(url).ok()?;
+        self.jar
+            .cookies(&url)
+            .and_then(|value| value.to_str().ok().map(str::to_string))
+    }
+    /// 创建共享 Cookie jar 的请求客户端
+    pub fn client(&self) -> PlayerResult<Client> {
+        Ok(Client::builder()
+            .cookie_provider(self.jar.clone())
+            .build()?)
+    }
+    /// 检查登录状态，没有凭据时不请求接口
+    pub async fn login_status(&self, client: &Client) -> PlayerResult<LoginStatus> {
+        if !self.credentials().has_session() {
+            return Ok(LoginStatus::default());
+        }
+        fetch_login_status(client).await
+    }
+}
+
+/// 登录状态
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoginStatus {
+    pub logged_in: bool, // 凭据是否有效
+    pub uname: String,   // 用户名
+    pub mid: u64,        // 用户 ID
+    pub vip: bool,       // 是否为大会员
+}
+
+/// 请求 nav 接口检查登录状态，未登录时接口返回 code -101
+pub async fn fetch_login_status(client: &Client) -> PlayerResult<LoginStatus> {
+    let json: Value = client
+        .get(NAV_API_URL)
+        .header("User-Agent", USER_AGENT)
+        .header("Referer", BILIBILI_URL)
+        .send()
+        .await
+        .map_err(|e| PlayerError::FetchError(format!("Fetch login status failed:{e}")))?
+        .json()
+        .await
+        .map_err(|e| PlayerError::FetchError(format!("Fetch login status failed:{e}")))?;
+    let data = &json["data"];
+    if !data.is_object() {
+        return Err(PlayerError::RespDataParsingError(
+            "解析登录状态失败".to_string(),
+        ));
+    }
+    Ok(LoginStatus {
+        logged_in: data["isLogin"].as_bool().unwrap_or(false),
+        uname: data["uname"].as_str().unwrap_or_default().to_string(),
+        mid: data["mid"].as_u64().unwrap_or_default(),
+        vip: data["vipStatus"].as_i64() == Some(1),
+    })
+}
@@ -1,2 +1,7 @@
+pub mod cdn;
+pub mod credentials;
 pub mod network;
+pub mod passport;
+pub mod stream;
 pub mod verify;
+pub mod wbi;
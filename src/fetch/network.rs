@@ -1,22 +1,73 @@
 use reqwest::Client;
 use serde_json::Value;
 
-use crate::errors::{PlayerError, PlayerResult};
+use crate::{
+    errors::{PlayerError, PlayerResult},
+    fetch::{
+        stream::{AudioStream, DASH_FNVAL, StreamSelector, parse_dash_audio},
+        wbi,
+    },
+};
 
-const BASE_FETCH_AUDIO_API_URL: &str = "https://api.bilibili.com/x/player/playurl?fnval=16";
-const BASE_FETCH_VIDEO_API_URL: &str = "https://api.bilibili.com/x/web-interface/view";
-/// 获取音频URL
+const BASE_FETCH_AUDIO_API_URL: &str = "https://api.bilibili.com/x/player/wbi/playurl";
+const BASE_FETCH_VIDEO_API_URL: &str = "https://api.bilibili.com/x/web-interface/wbi/view";
+/// WBI 签名无效或过期时接口返回的错误码
+const WBI_REJECTED_CODE: i64 = -352;
+
+/// 带 WBI 签名请求接口，签名被拒绝时刷新密钥后重试一次
+async fn get_signed_json(
+    client: &Client,
+    url: &str,
+    params: &[(&str, &str)],
+) -> PlayerResult<Value> {
+    let mut retried = false;
+    loop {
+        let query = wbi::signed_query(client, params).await?;
+        let json: Value = client
+            .get(format!("{}?{}", url, query))
+            .send()
+            .await?
+            .json()
+            .await?;
+        if json["code"].as_i64() == Some(WBI_REJECTED_CODE) && !retried {
+            tracing::warn!("WBI signature rejected, refreshing keys");
+            wbi::clear_cached_keys();
+            retried = true;
+            continue;
+        }
+        return Ok(json);
+    }
+}
+/// 获取视频的所有 DASH 音轨，包括杜比全景声和 Hi-Res 无损音轨
+///
+/// # 参数：
+/// - `client`: 请求客户端
+/// - `bvid`: 视频ID
+/// - `cid`: 视频分P ID
+/// # return
+/// - `PlayerResult<Vec<AudioStream>>`: 音轨列表
+pub async fn fetch_audio_streams(
+    client: &Client,
+    bvid: &str,
+    cid: &str,
+) -> PlayerResult<Vec<AudioStream>> {
+    let params = [("bvid", bvid), ("cid", cid), ("fnval", DASH_FNVAL)];
+    let json = get_signed_json(client, BASE_FETCH_AUDIO_API_URL, &params).await?;
+    parse_dash_audio(&json["data"])
+}
+/// 按音质偏好获取音频URL
 ///
 /// # 参数：
 /// - `client`: 请求客户端
 /// - `bvid`: 视频ID
 /// - `cid`: 视频分P ID
+/// - `selector`: 音质偏好
 /// # return
 /// - `PlayerResult<String>`: 音频URL
 /// # Examples
 ///
 /// ```
-/// use bili_player::fetch::network;
+/// use bili_player::fetch::{network, stream::StreamSelector};
 /// use reqwest::Client;
 ///
 /// #[tokio::main]
@@ -24,18 +75,24 @@ const BASE_FETCH_VIDEO_API_URL: &str = "https://api.bilibili.com/x/web-interface
 ///     let client = Client::new();
 ///     let bvid = "BV1r7411p7R4";
 ///     let cid = "321818216";
-///     let audio_url = network::fetch_audio_url(&client, bvid, cid).await.unwrap();
+///     let selector = StreamSelector::default();
+///     let audio_url = network::fetch_audio_url(&client, bvid, cid, &selector)
+///         .await
+///         .unwrap();
 ///     println!("Audio URL: {}", audio_url);
 /// }
 /// ```
-pub async fn fetch_audio_url(client: &Client, bvid: &str, cid: &str) -> PlayerResult<String> {
-    let url = format!("{}&bvid={}&cid={}", BASE_FETCH_AUDIO_API_URL, bvid, cid);
+pub async fn fetch_audio_url(
+    client: &Client,
+    bvid: &str,
+    cid: &str,
+    selector: &StreamSelector,
+) -> PlayerResult<String> {
     // tracing::info!("Fetching audio URL...");
-    let response = client.get(&url).send().await?;
-    let json: Value = response.json().await?;
-    json["data"]["dash"]["audio"][0]["baseUrl"]
-        .as_str()
-        .map(|s| s.to_string())
+    let streams = fetch_audio_streams(client, bvid, cid).await?;
+    selector
+        .select(&streams)
+        .map(|stream| stream.base_url.clone())
         .ok_or_else(|| PlayerError::RespDataParsingError("解析音频URL失败".to_string()))
 }
 #[derive(serde::Deserialize, Debug)]
@@ -49,6 +106,8 @@ pub struct VideoData {
     pub title: String,
     pub cid: i64,
     pub owner: Owner,
+    #[serde(default)]
+    pub duration: u64,
 }
 #[derive(serde::Deserialize, Debug)]
 struct ApiResponse<T> {
@@ -72,15 +131,10 @@ struct ApiResponse<T> {
 /// }
 /// ```
 pub async fn fetch_video_data(client: &Client, bvid: &str) -> PlayerResult<VideoData> {
-    let url = format!("{}?bvid={}", BASE_FETCH_VIDEO_API_URL, bvid);
-    let response = client
-        .get(&url)
-        .send()
+    let json = get_signed_json(client, BASE_FETCH_VIDEO_API_URL, &[("bvid", bvid)])
         .await
         .map_err(|e| PlayerError::FetchError(format!("Fetch video data failed:{e}")))?;
-    let mut api_response: ApiResponse<VideoData> = response
-        .json()
-        .await
+    let mut api_response: ApiResponse<VideoData> = serde_json::from_value(json)
         .map_err(|e| PlayerError::FetchError(format!("Fetch video data failed:{e}")))?;
     api_response.data.bvid = bvid.to_string();
     Ok(api_response.data)
@@ -0,0 +1,147 @@
+use qrcode::{QrCode, render::unicode::Dense1x2};
+use reqwest::{Client, header::SET_COOKIE};
+use serde_json::Value;
+
+use crate::{
+    errors::{PlayerError, PlayerResult},
+    fetch::{credentials::Credentials, wbi::USER_AGENT},
+};
+
+/// B 站通行证接口地址
+pub const PASSPORT_BASE_URL: &str = "https://passport.bilibili.com";
+const QRCODE_GENERATE_PATH: &str = "/x/passport-login/web/qrcode/generate";
+const QRCODE_POLL_PATH: &str = "/x/passport-login/web/qrcode/poll";
+
+/// 申请到的登录二维码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QrCodeLogin {
+    pub url: String,        // 二维码内容，用 B 站 App 扫描
+    pub qrcode_key: String, // 轮询扫码结果时使用的 key，180 秒内有效
+}
+
+/// 扫码登录的状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QrLoginStatus {
+    Waiting,                // 还没有扫码
+    Scanned,                // 已扫码，等待在手机上确认
+    Expired,                // 二维码已失效
+    Confirmed(Credentials), // 登录成功，返回登录凭据
+}
+impl QrLoginStatus {
+    /// 从轮询接口返回的状态码解析，0 表示登录成功，需要另外解析凭据
+    fn from_code(code: i64) -> Option<Self> {
+        match code {
+            86101 => Some(Self::Waiting),
+            86090 => Some(Self::Scanned),
+            86038 => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+
+/// 通行证扫码登录
+#[derive(Debug, Clone)]
+pub struct Passport {
+    client: Client,
+    base_url: String,
+}
+impl Passport {
+    /// Passport 构造函数
+    pub fn new(client: Client) -> Self {
+        Self::with_base_url(client, PASSPORT_BASE_URL)
+    }
+    /// 使用指定的接口地址，测试时指向本地的模拟服务
+    pub fn with_base_url(client: Client, base_url: &str) -> Self {
+        Self {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+    /// 申请登录二维码
+    pub async fn generate_qrcode(&self) -> PlayerResult<QrCodeLogin> {
+        let json: Value = self
+            .client
+            .get(format!("{}{}", self.base_url, QRCODE_GENERATE_PATH))
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await
+            .map_err(|e| PlayerError::FetchError(format!("Generate qrcode failed:{e}")))?
+            .json()
+            .await
+            .map_err(|e| PlayerError::FetchError(format!("Generate qrcode failed:{e}")))?;
+        if json["code"].as_i64() != Some(0) {
+            return Err(PlayerError::FetchError(format!(
+                "申请登录二维码失败: {}",
+                json["message"].as_str().unwrap_or_default()
+            )));
+        }
+        let url = json["data"]["url"].as_str();
+        let qrcode_key = json["data"]["qrcode_key"].as_str();
+        url.zip(qrcode_key)
+            .map(|(url, qrcode_key)| QrCodeLogin {
+                url: url.to_string(),
+                qrcode_key: qrcode_key.to_string(),
+            })
+            .ok_or_else(|| PlayerError::RespDataParsingError("解析登录二维码失败".to_string()))
+    }
+    /// 查询扫码结果，登录成功时从 Set-Cookie 中取出凭据
+    pub async fn poll(&self, qrcode_key: &str) -> PlayerResult<QrLoginStatus> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.base_url, QRCODE_POLL_PATH))
+            .query(&[("qrcode_key", qrcode_key)])
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await
+            .map_err(|e| PlayerError::FetchError(format!("Poll qrcode failed:{e}")))?;
+        let cookies = response
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let json: Value = response
+            .json()
+            .await
+            .map_err(|e| PlayerError::FetchError(format!("Poll qrcode failed:{e}")))?;
+        let code = json["data"]["code"]
+            .as_i64()
+            .ok_or_else(|| PlayerError::RespDataParsingError("解析扫码状态失败".to_string()))?;
+        if code != 0 {
+            return QrLoginStatus::from_code(code).ok_or_else(|| {
+                PlayerError::FetchError(format!(
+                    "扫码登录失败: {}",
+                    json["data"]["message"].as_str().unwrap_or_default()
+                ))
+            });
+        }
+        // 没有 Set-Cookie 时从跳转地址的查询参数中取，参数值就是编码后的 Cookie 值
+        let mut credentials = Credentials::parse_cookies(&cookies);
+        if !credentials.has_session()
+            && let Some((_, query)) = json["data"]["url"]
+                .as_str()
+                .and_then(|url| url.split_once('?'))
+        {
+            credentials = Credentials::parse_cookies(&query.replace('&', ";"));
+        }
+        if !credentials.has_session() {
+            return Err(PlayerError::RespDataParsingError(
+                "登录成功但没有返回 SESSDATA".to_string(),
+            ));
+        }
+        Ok(QrLoginStatus::Confirmed(credentials))
+    }
+}
+
+/// 把二维码渲染为终端中显示的 Unicode 方块，每个字符表示上下两个模块
+pub fn render_qrcode(content: &str) -> PlayerResult<String> {
+    let code = QrCode::new(content.as_bytes())
+        .map_err(|e| PlayerError::RespDataParsingError(format!("生成二维码失败: {e}")))?;
+    // 终端多为深色背景，反转颜色后手机更容易识别
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build())
+}
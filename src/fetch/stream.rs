@@ -0,0 +1,206 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::errors::{PlayerError, PlayerResult};
+
+/// 请求 playurl 时的 fnval，4048 表示请求 DASH 格式的所有音轨，包括杜比全景声和 Hi-Res 无损
+pub const DASH_FNVAL: &str = "4048";
+
+/// DASH 音轨的音质，对应 playurl 返回的 id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AudioQuality {
+    Standard, // 30216，64K
+    High,     // 30232，132K
+    VeryHigh, // 30280，192K
+    Dolby,    // 30250，杜比全景声
+    HiRes,    // 30251，Hi-Res 无损
+}
+impl AudioQuality {
+    pub fn get_string(&self) -> String {
+        match self {
+            AudioQuality::Standard => "64K".to_string(),
+            AudioQuality::High => "132K".to_string(),
+            AudioQuality::VeryHigh => "192K".to_string(),
+            AudioQuality::Dolby => "杜比全景声".to_string(),
+            AudioQuality::HiRes => "Hi-Res 无损".to_string(),
+        }
+    }
+    /// 从 playurl 返回的音轨 id 解析，无法识别时返回 None
+    pub fn from_id(id: u32) -> Option<AudioQuality> {
+        match id {
+            30216 => Some(AudioQuality::Standard),
+            30232 => Some(AudioQuality::High),
+            30280 => Some(AudioQuality::VeryHigh),
+            30250 => Some(AudioQuality::Dolby),
+            30251 => Some(AudioQuality::HiRes),
+            _ => None,
+        }
+    }
+    /// 标称码率，杜比和无损音轨没有固定码率
+    pub fn nominal_kbps(&self) -> Option<u32> {
+        match self {
+            AudioQuality::Standard => Some(64),
+            AudioQuality::High => Some(132),
+            AudioQuality::VeryHigh => Some(192),
+            AudioQuality::Dolby | AudioQuality::HiRes => None,
+        }
+    }
+}
+/// 转换为 gRPC 中的音质
+impl From<AudioQuality> for crate::pb::AudioQuality {
+    fn from(quality: AudioQuality) -> Self {
+        match quality {
+            AudioQuality::Standard => Self::Standard,
+            AudioQuality::High => Self::High,
+            AudioQuality::VeryHigh => Self::VeryHigh,
+            AudioQuality::Dolby => Self::Dolby,
+            AudioQuality::HiRes => Self::HiRes,
+        }
+    }
+}
+/// 从 gRPC 中的音质转换，未指定时返回 None
+impl From<crate::pb::AudioQuality> for Option<AudioQuality> {
+    fn from(quality: crate::pb::AudioQuality) -> Self {
+        match quality {
+            crate::pb::AudioQuality::Unspecified => None,
+            crate::pb::AudioQuality::Standard => Some(AudioQuality::Standard),
+            crate::pb::AudioQuality::High => Some(AudioQuality::High),
+            crate::pb::AudioQuality::VeryHigh => Some(AudioQuality::VeryHigh),
+            crate::pb::AudioQuality::Dolby => Some(AudioQuality::Dolby),
+            crate::pb::AudioQuality::HiRes => Some(AudioQuality::HiRes),
+        }
+    }
+}
+
+/// playurl 返回的一条 DASH 音轨
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AudioStream {
+    pub id: u32,
+    #[serde(rename = "baseUrl")]
+    pub base_url: String, // 主地址
+    #[serde(rename = "backupUrl", default, deserialize_with = "null_as_default")]
+    pub backup_urls: Vec<String>, // 备用地址，可能为 null
+    #[serde(default)]
+    pub bandwidth: u64, // 码率，单位为 bps
+    #[serde(default)]
+    pub codecs: String, // 编码，例如 mp4a.40.2、ec-3、fLaC
+}
+impl AudioStream {
+    /// 音轨的音质，无法识别的 id 返回 None
+    pub fn quality(&self) -> Option<AudioQuality> {
+        AudioQuality::from_id(self.id)
+    }
+    /// 码率，普通音轨使用标称码率，其他音轨按 bandwidth 计算
+    pub fn kbps(&self) -> u32 {
+        self.quality()
+            .and_then(|quality| quality.nominal_kbps())
+            .unwrap_or((self.bandwidth / 1000) as u32)
+    }
+}
+/// 把 null 当作默认值处理
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// 解析 playurl 返回的 data，合并普通、杜比和 Hi-Res 音轨
+pub fn parse_dash_audio(data: &Value) -> PlayerResult<Vec<AudioStream>> {
+    let dash = &data["dash"];
+    let parse = |value: &Value| {
+        serde_json::from_value::<AudioStream>(value.clone())
+            .map_err(|e| PlayerError::RespDataParsingError(format!("解析音轨失败: {e}")))
+    };
+    let mut streams = Vec::new();
+    for list in [&dash["audio"], &dash["dolby"]["audio"]] {
+        for value in list.as_array().into_iter().flatten() {
+            streams.push(parse(value)?);
+        }
+    }
+    if dash["flac"]["audio"].is_object() {
+        streams.push(parse(&dash["flac"]["audio"])?);
+    }
+    if streams.is_empty() {
+        return Err(PlayerError::RespDataParsingError(
+            "没有可用的音轨".to_string(),
+        ));
+    }
+    Ok(streams)
+}
+
+/// 音质偏好
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioPreference {
+    Max,          // 最高音质，包括杜比全景声和 Hi-Res 无损
+    Bitrate(u32), // 不超过指定码率（kbps）的最高音质
+    DataSaver,    // 省流量，选择码率最低的普通音轨
+}
+impl AudioPreference {
+    pub fn get_string(&self) -> String {
+        match self {
+            AudioPreference::Max => "最高音质".to_string(),
+            AudioPreference::Bitrate(kbps) => format!("不超过 {}K", kbps),
+            AudioPreference::DataSaver => "省流量".to_string(),
+        }
+    }
+    /// 从字符串解析音质偏好，支持 max、data_saver 和 192k 形式的码率，无法识别时返回 None
+    pub fn from_string(s: &str) -> Option<AudioPreference> {
+        let s = s.trim().to_lowercase();
+        match s.as_str() {
+            "max" => Some(AudioPreference::Max),
+            "data_saver" | "saver" => Some(AudioPreference::DataSaver),
+            _ => s
+                .strip_suffix('k')
+                .unwrap_or(&s)
+                .parse()
+                .ok()
+                .filter(|&kbps| kbps > 0)
+                .map(AudioPreference::Bitrate),
+        }
+    }
+}
+
+/// 默认的音质偏好：192K 以内码率最高的 AAC 音轨
+///
+/// 杜比全景声（E-AC-3）和 Hi-Res（FLAC）需要额外的 GStreamer 插件才能解码，默认不选。
+pub const DEFAULT_AUDIO_PREFERENCE: AudioPreference = AudioPreference::Bitrate(192);
+
+/// 按音质偏好从 DASH 音轨中选择一条
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamSelector {
+    pub preference: AudioPreference,
+}
+impl Default for StreamSelector {
+    fn default() -> Self {
+        Self::new(DEFAULT_AUDIO_PREFERENCE)
+    }
+}
+impl StreamSelector {
+    /// StreamSelector 构造函数
+    pub fn new(preference: AudioPreference) -> Self {
+        Self { preference }
+    }
+    /// 选择音轨，没有满足偏好的音轨时退回码率最低的一条
+    pub fn select<'a>(&self, streams: &'a [AudioStream]) -> Option<&'a AudioStream> {
+        // 音质相同时比较 bandwidth，无法识别的音轨排在最后
+        let rank = |stream: &&AudioStream| (stream.quality(), stream.bandwidth);
+        let lowest = || {
+            streams
+                .iter()
+                .filter(|stream| stream.quality().is_none_or(|q| q.nominal_kbps().is_some()))
+                .min_by_key(|stream| (stream.kbps(), stream.bandwidth))
+                .or_else(|| streams.iter().min_by_key(|stream| stream.bandwidth))
+        };
+        match self.preference {
+            AudioPreference::Max => streams.iter().max_by_key(rank),
+            AudioPreference::Bitrate(kbps) => streams
+                .iter()
+                .filter(|stream| stream.kbps() <= kbps)
+                .max_by_key(rank)
+                .or_else(lowest),
+            AudioPreference::DataSaver => lowest(),
+        }
+    }
+}
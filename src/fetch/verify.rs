@@ -8,9 +8,16 @@ use tokio::time::sleep;
 
 use crate::{
     errors::{PlayerError, PlayerResult},
-    fetch::network::fetch_audio_url,
+    fetch::{
+        cdn::CdnHosts,
+        network::fetch_audio_streams,
+        stream::{AudioStream, StreamSelector},
+    },
 };
 
+/// 验证单个地址的超时时间，避免卡在不可用的 CDN 上
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// 验证音频 URL 是否可用
 ///
 /// # 参数
@@ -25,6 +32,7 @@ pub async fn verify_audio_url(client: &Client, url: &str) -> PlayerResult<bool>
         .header(ACCEPT, "*/*")
         .header(RANGE, "bytes=0-1024")
         .header("Referer", "https://www.bilibili.com")
+        .timeout(VERIFY_TIMEOUT)
         .send()
         .await
         .map_err(|e| PlayerError::NetworkError(e.to_string()))?;
@@ -32,18 +40,24 @@ pub async fn verify_audio_url(client: &Client, url: &str) -> PlayerResult<bool>
     Ok(response.status().is_success())
 }
 
-/// 请求并验证音频 URL 是否可用，如果不可用则重试 3 次
+/// 按音质偏好选择音轨，依次验证主地址、备用地址和备选 CDN 地址
+///
+/// 只有所有候选地址都不可用时才重新请求接口，最多请求 3 次。
 ///
 /// # 参数
 /// - `client`: 请求客户端
 /// - `bvid`: 视频 ID
 /// - `cid`: 视频分 P ID
+/// - `selector`: 音轨选择器
+/// - `cdn`: 候选地址顺序和上次可用的 CDN 域名
 /// # 返回值
-/// - `PlayerResult<String>`: 验证成功的音频 URL
+/// - `PlayerResult<(AudioStream, String)>`: 选中的音轨和验证可用的地址
 /// # Examples
 ///
 /// ```
-/// use bili_player::fetch::verify::fetch_and_verify_audio_url;
+/// use bili_player::fetch::{
+///     cdn::CdnHosts, stream::StreamSelector, verify::fetch_and_verify_audio_stream,
+/// };
 /// use reqwest::Client;
 ///
 /// #[tokio::main]
@@ -52,18 +66,21 @@ pub async fn verify_audio_url(client: &Client, url: &str) -> PlayerResult<bool>
 ///     let bvid = "BV1r7411p7R4";
 ///     let cid = "321818216";
 ///
-///     match fetch_and_verify_audio_url(&client, bvid, cid).await {
-///         Ok(url) => println!("Audio URL verified: {}", url),
+///     let (selector, cdn) = (StreamSelector::default(), CdnHosts::default());
+///     match fetch_and_verify_audio_stream(&client, bvid, cid, &selector, &cdn).await {
+///         Ok((_, url)) => println!("Audio URL verified: {}", url),
 ///         Err(e) => println!("Error verifying audio URL: {}", e),
 ///     }
 /// }
 /// ```
 ///
-pub async fn fetch_and_verify_audio_url(
+pub async fn fetch_and_verify_audio_stream(
     client: &Client,
     bvid: &str,
     cid: &str,
-) -> PlayerResult<String> {
+    selector: &StreamSelector,
+    cdn: &CdnHosts,
+) -> PlayerResult<(AudioStream, String)> {
     // 最大重试次数
     const MAX_RETRIES: u32 = 3;
     // 最初的重试延迟
@@ -72,14 +89,19 @@ pub async fn fetch_and_verify_audio_url(
     let mut retry_delay = INITIAL_RETRY_DELAY;
 
     for attempt in 1..=MAX_RETRIES {
-        match fetch_audio_url(client, bvid, cid).await {
-            Ok(url) => match verify_audio_url(client, &url).await {
-                Ok(true) => return Ok(url),
-                Ok(false) => {
-                    tracing::info!("Verification failed for URL: {}", url);
-                }
-                Err(e) => {
-                    tracing::error!("Error verifying URL: {}", e);
+        let stream = fetch_audio_streams(client, bvid, cid)
+            .await
+            .and_then(|streams| {
+                selector
+                    .select(&streams)
+                    .cloned()
+                    .ok_or_else(|| PlayerError::RespDataParsingError("没有可用的音轨".to_string()))
+            });
+        match stream {
+            Ok(stream) => match cdn.find_working_url(client, &stream).await {
+                Some(url) => return Ok((stream, url)),
+                None => {
+                    tracing::warn!("All candidate URLs failed for {}", bvid);
                 }
             },
             Err(e) => {
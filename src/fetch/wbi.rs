@@ -0,0 +1,142 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::errors::{PlayerError, PlayerResult};
+
+/// 获取 WBI 签名密钥的接口，未登录时 code 为 -101，但仍然会返回密钥
+pub const NAV_API_URL: &str = "https://api.bilibili.com/x/web-interface/nav";
+/// 请求 nav 接口时使用的浏览器 UA，没有 UA 的请求可能被拒绝
+pub const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+/// 密钥每天更换，缓存超过这个时间后重新获取
+const KEYS_CACHE_DURATION: Duration = Duration::from_secs(60 * 60);
+
+/// 打乱 img_key + sub_key 得到 mixin key 的重排表
+pub const MIXIN_KEY_ENC_TAB: [usize; 64] = [
+    46, 47, 18, 2, 53, 8, 23, 32, 15, 50, 10, 31, 58, 3, 45, 35, 27, 43, 5, 49, 33, 9, 42, 19, 29,
+    28, 14, 39, 12, 38, 41, 13, 37, 48, 7, 16, 24, 55, 40, 61, 26, 17, 0, 1, 60, 51, 30, 4, 22, 25,
+    54, 21, 56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
+];
+
+/// 缓存的密钥和获取时间
+static CACHED_KEYS: Mutex<Option<(WbiKeys, Instant)>> = Mutex::new(None);
+
+/// nav 接口返回的 WBI 密钥
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WbiKeys {
+    pub img_key: String,
+    pub sub_key: String,
+}
+impl WbiKeys {
+    /// 从 nav 接口返回的图片地址中取出密钥，例如 `https://i0.hdslb.com/bfs/wbi/<key>.png`
+    pub fn from_urls(img_url: &str, sub_url: &str) -> Option<Self> {
+        let key = |url: &str| {
+            let name = url.rsplit('/').next()?;
+            let key = name.split('.').next()?;
+            (!key.is_empty()).then(|| key.to_string())
+        };
+        Some(Self {
+            img_key: key(img_url)?,
+            sub_key: key(sub_url)?,
+        })
+    }
+    /// 按重排表打乱 img_key + sub_key，取前 32 位作为签名用的 mixin key
+    pub fn mixin_key(&self) -> String {
+        let raw: Vec<char> = format!("{}{}", self.img_key, self.sub_key)
+            .chars()
+            .collect();
+        MIXIN_KEY_ENC_TAB
+            .iter()
+            .filter_map(|&i| raw.get(i))
+            .take(32)
+            .collect()
+    }
+}
+
+/// 对查询参数签名，返回带 wts 和 w_rid 的查询字符串
+///
+/// 参数按名称排序，值中的 `!'()*` 会被去掉，w_rid 为查询字符串拼接 mixin key 后的 MD5。
+///
+/// # Examples
+///
+/// ```
+/// use bili_player::fetch::wbi::{WbiKeys, sign_params};
+///
+/// let keys = WbiKeys {
+///     img_key: "7cd084941338484aae1ad9425b84077c".to_string(),
+///     sub_key: "4932caff0ff746eab6f01bf08b70ac45".to_string(),
+/// };
+/// let query = sign_params(&[("foo", "114")], &keys.mixin_key(), 1702204169);
+/// assert!(query.starts_with("foo=114&wts=1702204169&w_rid="));
+/// ```
+pub fn sign_params(params: &[(&str, &str)], mixin_key: &str, wts: u64) -> String {
+    let wts = wts.to_string();
+    let mut params: Vec<(&str, &str)> = params.to_vec();
+    params.push(("wts", &wts));
+    params.sort_by_key(|&(key, _)| key);
+    let query = params
+        .iter()
+        .map(|(key, value)| {
+            let value: String = value.chars().filter(|c| !"!'()*".contains(*c)).collect();
+            format!(
+                "{}={}",
+                urlencoding::encode(key),
+                urlencoding::encode(&value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    let w_rid = md5::compute(format!("{}{}", query, mixin_key));
+    format!("{}&w_rid={:x}", query, w_rid)
+}
+
+/// 请求 nav 接口获取最新的密钥
+pub async fn fetch_wbi_keys(client: &Client) -> PlayerResult<WbiKeys> {
+    let json: Value = client
+        .get(NAV_API_URL)
+        .header("User-Agent", USER_AGENT)
+        .header("Referer", "https://www.bilibili.com")
+        .send()
+        .await
+        .map_err(|e| PlayerError::FetchError(format!("Fetch wbi keys failed:{e}")))?
+        .json()
+        .await
+        .map_err(|e| PlayerError::FetchError(format!("Fetch wbi keys failed:{e}")))?;
+    let img_url = json["data"]["wbi_img"]["img_url"].as_str();
+    let sub_url = json["data"]["wbi_img"]["sub_url"].as_str();
+    img_url
+        .zip(sub_url)
+        .and_then(|(img_url, sub_url)| WbiKeys::from_urls(img_url, sub_url))
+        .ok_or_else(|| PlayerError::RespDataParsingError("解析 WBI 密钥失败".to_string()))
+}
+
+/// 获取密钥，缓存过期时重新请求
+pub async fn wbi_keys(client: &Client) -> PlayerResult<WbiKeys> {
+    if let Some((keys, fetched_at)) = CACHED_KEYS.lock().unwrap().as_ref()
+        && fetched_at.elapsed() < KEYS_CACHE_DURATION
+    {
+        return Ok(keys.clone());
+    }
+    let keys = fetch_wbi_keys(client).await?;
+    *CACHED_KEYS.lock().unwrap() = Some((keys.clone(), Instant::now()));
+    Ok(keys)
+}
+
+/// 清除缓存的密钥，签名被拒绝（code -352）时调用，下次请求会重新获取
+pub fn clear_cached_keys() {
+    CACHED_KEYS.lock().unwrap().take();
+}
+
+/// 使用当前时间和缓存的密钥对查询参数签名
+pub async fn signed_query(client: &Client, params: &[(&str, &str)]) -> PlayerResult<String> {
+    let keys = wbi_keys(client).await?;
+    let wts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    Ok(sign_params(params, &keys.mixin_key(), wts))
+}
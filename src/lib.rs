@@ -1,5 +1,8 @@
+pub mod auth;
+pub mod config;
 pub mod errors;
 pub mod fetch;
+pub mod library;
 pub mod logger;
 pub mod pb;
 pub mod player;
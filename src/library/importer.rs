@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use crate::{
+    errors::PlayerResult,
+    library::repository::MusicRepository,
+    player::music_data::{MusicParseError, parse_music_file},
+};
+
+/// 导入结果
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub imported: usize,               // 写入（新增或更新）的歌曲数量
+    pub skipped: Vec<MusicParseError>, // 被跳过的行
+}
+
+/// 把 musics.txt 格式的文件导入到 `musics` 表
+///
+/// 按 bvid 执行 upsert，重复导入同一个文件不会产生重复记录。空行会被忽略，
+/// 无法解析的行记录在返回结果的 `skipped` 中。
+///
+/// # 参数
+/// - `repository`: 音乐库仓储
+/// - `path`: 文件路径
+/// # 返回值
+/// - `PlayerResult<ImportReport>`: 导入结果
+pub async fn import_music_file(
+    repository: &MusicRepository,
+    path: impl AsRef<Path>,
+) -> PlayerResult<ImportReport> {
+    let mut report = ImportReport::default();
+    for result in parse_music_file(path)? {
+        match result {
+            Ok(music) => {
+                repository.upsert(&music).await?;
+                report.imported += 1;
+            }
+            Err(e) => report.skipped.push(e),
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,35 @@
+pub mod importer;
+pub mod playlists;
+pub mod repository;
+pub mod session;
+
+use std::str::FromStr;
+
+use sqlx::{
+    SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+
+use crate::errors::PlayerResult;
+
+/// 默认的数据库地址，与 `.env` 中的 DATABASE_URL 保持一致
+pub const DEFAULT_DATABASE_URL: &str = "sqlite:musics_data.db";
+
+/// 连接 SQLite 数据库并执行 `migrations` 目录下的迁移
+///
+/// # 参数
+/// - `database_url`: 数据库地址，例如 `sqlite:musics_data.db`
+/// # 返回值
+/// - `PlayerResult<SqlitePool>`: 数据库连接池
+pub async fn connect(database_url: &str) -> PlayerResult<SqlitePool> {
+    // 数据库文件不存在时自动创建
+    let options = SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await?;
+    // 执行迁移
+    sqlx::migrate!("./migrations").run(&pool).await?;
+    tracing::info!("Connected to music library: {}", database_url);
+    Ok(pool)
+}
@@ -0,0 +1,192 @@
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::{PlayerError, PlayerResult},
+    library::repository::MusicRecord,
+};
+
+/// `playlists` 表中的一个歌单，附带歌曲数量
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PlaylistRecord {
+    pub id: i64,
+    pub name: String,
+    pub last_bvid: Option<String>,
+    pub track_count: i64,
+}
+
+const SELECT_PLAYLIST: &str = "SELECT p.id, p.name, p.last_bvid, \
+     (SELECT COUNT(*) FROM playlist_items i JOIN musics m ON m.id = i.music_id \
+      WHERE i.playlist_id = p.id AND m.is_deleted = 0) AS track_count \
+     FROM playlists p";
+
+/// 歌单仓储，封装对 `playlists` 和 `playlist_items` 表的读写
+#[derive(Debug, Clone)]
+pub struct PlaylistRepository {
+    pool: SqlitePool,
+}
+impl PlaylistRepository {
+    /// PlaylistRepository 构造函数
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+    /// 创建歌单，名称重复时返回 AlreadyExists
+    pub async fn create(&self, name: &str) -> PlayerResult<PlaylistRecord> {
+        if self.find_by_name(name).await?.is_some() {
+            return Err(PlayerError::AlreadyExists(format!(
+                "playlist {} already exists",
+                name
+            )));
+        }
+        let id = sqlx::query("INSERT INTO playlists (name) VALUES (?)")
+            .bind(name)
+            .execute(&self.pool)
+            .await?
+            .last_insert_rowid();
+        Ok(PlaylistRecord {
+            id,
+            name: name.to_string(),
+            last_bvid: None,
+            track_count: 0,
+        })
+    }
+    /// 按名称查找歌单
+    pub async fn find_by_name(&self, name: &str) -> PlayerResult<Option<PlaylistRecord>> {
+        let record =
+            sqlx::query_as::<_, PlaylistRecord>(&format!("{SELECT_PLAYLIST} WHERE p.name = ?"))
+                .bind(name)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(record)
+    }
+    /// 按 id 查找歌单
+    pub async fn find_by_id(&self, id: i64) -> PlayerResult<Option<PlaylistRecord>> {
+        let record =
+            sqlx::query_as::<_, PlaylistRecord>(&format!("{SELECT_PLAYLIST} WHERE p.id = ?"))
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(record)
+    }
+    /// 按名称获取歌单，不存在时返回 NotFound
+    pub async fn get_by_name(&self, name: &str) -> PlayerResult<PlaylistRecord> {
+        self.find_by_name(name)
+            .await?
+            .ok_or_else(|| PlayerError::NotFound(format!("playlist {} not found", name)))
+    }
+    /// 获取所有歌单，按创建顺序排列
+    pub async fn list(&self) -> PlayerResult<Vec<PlaylistRecord>> {
+        let records =
+            sqlx::query_as::<_, PlaylistRecord>(&format!("{SELECT_PLAYLIST} ORDER BY p.id"))
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(records)
+    }
+    /// 重命名歌单
+    pub async fn rename(&self, name: &str, new_name: &str) -> PlayerResult<()> {
+        let playlist = self.get_by_name(name).await?;
+        if self.find_by_name(new_name).await?.is_some() {
+            return Err(PlayerError::AlreadyExists(format!(
+                "playlist {} already exists",
+                new_name
+            )));
+        }
+        sqlx::query("UPDATE playlists SET name = ? WHERE id = ?")
+            .bind(new_name)
+            .bind(playlist.id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+    /// 删除歌单，歌单中的歌曲仍保留在音乐库中
+    pub async fn delete(&self, name: &str) -> PlayerResult<()> {
+        let playlist = self.get_by_name(name).await?;
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM playlist_items WHERE playlist_id = ?")
+            .bind(playlist.id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM playlists WHERE id = ?")
+            .bind(playlist.id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+    /// 把音乐库中的一首歌追加到歌单末尾
+    pub async fn add_music(&self, playlist_id: i64, music_id: i64) -> PlayerResult<()> {
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM playlist_items WHERE playlist_id = ? AND music_id = ?)",
+        )
+        .bind(playlist_id)
+        .bind(music_id)
+        .fetch_one(&self.pool)
+        .await?;
+        if exists {
+            return Err(PlayerError::AlreadyExists(format!(
+                "music {} is already in playlist",
+                music_id
+            )));
+        }
+        sqlx::query(
+            "INSERT INTO playlist_items (playlist_id, music_id, position) \
+             SELECT ?, ?, COALESCE(MAX(position) + 1, 0) FROM playlist_items WHERE playlist_id = ?",
+        )
+        .bind(playlist_id)
+        .bind(music_id)
+        .bind(playlist_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+    /// 从歌单中移除一首歌，返回是否有记录被移除
+    pub async fn remove_music(&self, playlist_id: i64, bvid: &str) -> PlayerResult<bool> {
+        let result = sqlx::query(
+            "DELETE FROM playlist_items WHERE playlist_id = ? \
+             AND music_id IN (SELECT id FROM musics WHERE bvid = ?)",
+        )
+        .bind(playlist_id)
+        .bind(bvid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+    /// 按歌单顺序获取歌单中未删除的音乐
+    pub async fn list_musics(&self, playlist_id: i64) -> PlayerResult<Vec<MusicRecord>> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "SELECT m.id, m.bvid, m.song_name, m.cid, m.songer, m.author, m.duration, \
+             m.is_liked, m.is_deleted, m.created_at, m.updated_at \
+             FROM playlist_items i JOIN musics m ON m.id = i.music_id \
+             WHERE i.playlist_id = ? AND m.is_deleted = 0 ORDER BY i.position",
+        )
+        .bind(playlist_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+    /// 按给定的 bvid 顺序重写歌单中歌曲的位置，不在列表中的歌曲位置不变
+    pub async fn set_order(&self, playlist_id: i64, bvids: &[String]) -> PlayerResult<()> {
+        let mut tx = self.pool.begin().await?;
+        for (position, bvid) in bvids.iter().enumerate() {
+            sqlx::query(
+                "UPDATE playlist_items SET position = ? WHERE playlist_id = ? \
+                 AND music_id IN (SELECT id FROM musics WHERE bvid = ?)",
+            )
+            .bind(position as i64)
+            .bind(playlist_id)
+            .bind(bvid)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+    /// 记录歌单上次播放到的歌曲
+    pub async fn set_last_bvid(&self, playlist_id: i64, bvid: Option<&str>) -> PlayerResult<()> {
+        sqlx::query("UPDATE playlists SET last_bvid = ? WHERE id = ?")
+            .bind(bvid)
+            .bind(playlist_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
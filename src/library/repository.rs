@@ -0,0 +1,177 @@
+use sqlx::{SqlitePool, types::chrono::NaiveDateTime};
+
+use crate::{errors::PlayerResult, player::model::MusicInfo};
+
+/// `musics` 表中的一行记录
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct MusicRecord {
+    pub id: i64,
+    pub bvid: String,
+    pub song_name: String,
+    pub cid: String,
+    pub songer: String,
+    pub author: Option<String>,
+    pub duration: Option<i64>,
+    pub is_liked: bool,
+    pub is_deleted: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+/// 把数据库记录转换为播放列表使用的音乐信息
+impl From<MusicRecord> for MusicInfo {
+    fn from(record: MusicRecord) -> Self {
+        Self {
+            bvid: record.bvid,
+            cid: record.cid,
+            title: record.song_name,
+            // 歌手为空字符串时视为未知
+            artist: Some(record.songer).filter(|songer| !songer.is_empty()),
+            owner: record.author.unwrap_or_default(),
+            duration: record.duration.unwrap_or(0).max(0) as u64,
+        }
+    }
+}
+
+const SELECT_MUSIC: &str = "SELECT id, bvid, song_name, cid, songer, author, duration, \
+     is_liked, is_deleted, created_at, updated_at FROM musics";
+
+/// 音乐库仓储，封装对 `musics` 表的读写
+#[derive(Debug, Clone)]
+pub struct MusicRepository {
+    pool: SqlitePool,
+}
+impl MusicRepository {
+    /// MusicRepository 构造函数
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+    /// 获取数据库连接池
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+    /// 插入一首音乐，bvid 重复时返回错误
+    pub async fn insert(&self, music: &MusicInfo) -> PlayerResult<i64> {
+        let result = sqlx::query(
+            "INSERT INTO musics (bvid, song_name, cid, songer, author, duration) \
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&music.bvid)
+        .bind(&music.title)
+        .bind(&music.cid)
+        .bind(music.artist.as_deref().unwrap_or_default())
+        .bind(&music.owner)
+        .bind(music.duration as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.last_insert_rowid())
+    }
+    /// 按 bvid 插入或更新一首音乐，已软删除的记录会被恢复
+    pub async fn upsert(&self, music: &MusicInfo) -> PlayerResult<i64> {
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO musics (bvid, song_name, cid, songer, author, duration) \
+             VALUES (?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(bvid) DO UPDATE SET \
+                 song_name = excluded.song_name, \
+                 cid = excluded.cid, \
+                 songer = excluded.songer, \
+                 author = excluded.author, \
+                 duration = excluded.duration, \
+                 is_deleted = 0 \
+             RETURNING id",
+        )
+        .bind(&music.bvid)
+        .bind(&music.title)
+        .bind(&music.cid)
+        .bind(music.artist.as_deref().unwrap_or_default())
+        .bind(&music.owner)
+        .bind(music.duration as i64)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(id)
+    }
+    /// 按 bvid 查找未删除的音乐
+    pub async fn find_by_bvid(&self, bvid: &str) -> PlayerResult<Option<MusicRecord>> {
+        let record = sqlx::query_as::<_, MusicRecord>(&format!(
+            "{SELECT_MUSIC} WHERE bvid = ? AND is_deleted = 0"
+        ))
+        .bind(bvid)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+    /// 软删除音乐，返回是否有记录被删除
+    pub async fn soft_delete(&self, bvid: &str) -> PlayerResult<bool> {
+        let result =
+            sqlx::query("UPDATE musics SET is_deleted = 1 WHERE bvid = ? AND is_deleted = 0")
+                .bind(bvid)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+    /// 标记为喜欢
+    pub async fn like(&self, bvid: &str) -> PlayerResult<bool> {
+        self.set_liked(bvid, true).await
+    }
+    /// 取消喜欢
+    pub async fn unlike(&self, bvid: &str) -> PlayerResult<bool> {
+        self.set_liked(bvid, false).await
+    }
+    /// 获取所有未删除的音乐，按添加顺序排列
+    pub async fn list_all(&self) -> PlayerResult<Vec<MusicRecord>> {
+        let records = sqlx::query_as::<_, MusicRecord>(&format!(
+            "{SELECT_MUSIC} WHERE is_deleted = 0 ORDER BY id"
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+    /// 分页获取未删除的音乐，页码从 1 开始
+    pub async fn list_paged(&self, page: u32, page_size: u32) -> PlayerResult<Vec<MusicRecord>> {
+        let offset = page.saturating_sub(1) as i64 * page_size as i64;
+        let records = sqlx::query_as::<_, MusicRecord>(&format!(
+            "{SELECT_MUSIC} WHERE is_deleted = 0 ORDER BY id LIMIT ? OFFSET ?"
+        ))
+        .bind(page_size as i64)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+    /// 获取未删除的音乐数量
+    pub async fn count(&self) -> PlayerResult<i64> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM musics WHERE is_deleted = 0")
+            .fetch_one(&self.pool)
+            .await?;
+        Ok(count)
+    }
+    /// 按歌手查询
+    pub async fn list_by_singer(&self, songer: &str) -> PlayerResult<Vec<MusicRecord>> {
+        let records = sqlx::query_as::<_, MusicRecord>(&format!(
+            "{SELECT_MUSIC} WHERE songer = ? AND is_deleted = 0 ORDER BY id"
+        ))
+        .bind(songer)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+    /// 按作者（UP主）查询
+    pub async fn list_by_author(&self, author: &str) -> PlayerResult<Vec<MusicRecord>> {
+        let records = sqlx::query_as::<_, MusicRecord>(&format!(
+            "{SELECT_MUSIC} WHERE author = ? AND is_deleted = 0 ORDER BY id"
+        ))
+        .bind(author)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+    /// 设置喜欢标记
+    async fn set_liked(&self, bvid: &str, liked: bool) -> PlayerResult<bool> {
+        let result =
+            sqlx::query("UPDATE musics SET is_liked = ? WHERE bvid = ? AND is_deleted = 0")
+                .bind(liked)
+                .bind(bvid)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,59 @@
+use sqlx::SqlitePool;
+
+use crate::errors::PlayerResult;
+
+/// `session` 表中保存的播放器状态
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct SessionRecord {
+    pub playlist_id: Option<i64>,     // 正在使用的歌单，None 表示音乐库
+    pub current_bvid: Option<String>, // 当前歌曲
+    pub play_mode: String,            // 播放模式
+    pub shuffle_loop: bool,           // 随机播放完一轮后是否继续
+    pub volume: i64,                  // 音量百分比
+    pub position_ms: i64,             // 当前歌曲播放到的位置（毫秒）
+    pub library_bvid: Option<String>, // 音乐库上次播放到的歌曲，使用歌单时切换回音乐库从这里继续
+}
+
+/// 会话仓储，读写 `session` 表中唯一的一行
+#[derive(Debug, Clone)]
+pub struct SessionRepository {
+    pool: SqlitePool,
+}
+impl SessionRepository {
+    /// SessionRepository 构造函数
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+    /// 读取上次保存的会话，从未保存过时返回 None
+    pub async fn load(&self) -> PlayerResult<Option<SessionRecord>> {
+        let record = sqlx::query_as::<_, SessionRecord>(
+            "SELECT playlist_id, current_bvid, play_mode, shuffle_loop, volume, position_ms, \
+             library_bvid FROM session WHERE id = 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+    /// 保存会话，覆盖上一次的记录
+    pub async fn save(&self, session: &SessionRecord) -> PlayerResult<()> {
+        sqlx::query(
+            "INSERT INTO session (id, playlist_id, current_bvid, play_mode, shuffle_loop, volume, position_ms, library_bvid) \
+             VALUES (1, ?, ?, ?, ?, ?, ?, ?) \
+             ON CONFLICT(id) DO UPDATE SET playlist_id = excluded.playlist_id, \
+             current_bvid = excluded.current_bvid, play_mode = excluded.play_mode, \
+             shuffle_loop = excluded.shuffle_loop, volume = excluded.volume, \
+             position_ms = excluded.position_ms, library_bvid = excluded.library_bvid, \
+             updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(session.playlist_id)
+        .bind(&session.current_bvid)
+        .bind(&session.play_mode)
+        .bind(session.shuffle_loop)
+        .bind(session.volume)
+        .bind(session.position_ms)
+        .bind(&session.library_bvid)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{path::Path, str::FromStr};
 use tracing_subscriber::{Layer as _, layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::utils::LocalTimer;
@@ -8,6 +8,17 @@ use crate::utils::LocalTimer;
 /// # 参数
 /// - logger_level: 日志等级
 pub async fn init_logger(logger_level: &str) -> anyhow::Result<()> {
+    init_logger_with_file(logger_level, None).await
+}
+/// 初始化日志，同时写入日志文件
+///
+/// # 参数
+/// - logger_level: 日志等级
+/// - log_dir: 日志文件目录，文件按天滚动；为 None 时只输出到终端
+pub async fn init_logger_with_file(
+    logger_level: &str,
+    log_dir: Option<&Path>,
+) -> anyhow::Result<()> {
     // set logger level form params if there had error use default info level
     let level = tracing::level_filters::LevelFilter::from_str(logger_level)
         .unwrap_or(tracing::level_filters::LevelFilter::INFO);
@@ -23,8 +34,21 @@ pub async fn init_logger(logger_level: &str) -> anyhow::Result<()> {
         .with_writer(std::io::stdout)
         .with_filter(level);
 
+    // 日志文件不需要终端的颜色控制符
+    let file_layer = log_dir.map(|dir| {
+        tracing_subscriber::fmt::Layer::default()
+            .with_ansi(false)
+            .with_file(true)
+            .with_line_number(true)
+            .with_target(false)
+            .with_timer(LocalTimer)
+            .with_writer(tracing_appender::rolling::daily(dir, "server.log"))
+            .with_filter(level)
+    });
+
     tracing_subscriber::registry()
         .with(stdout_layer) // 输出到终端
+        .with(file_layer) // 输出到日志文件
         .init();
     Ok(())
 }
@@ -1,9 +1,12 @@
-use bili_player::player::music_data::read_music_data;
+use bili_player::player::music_data::parse_music_file;
 
-fn main() {
+fn main() -> anyhow::Result<()> {
     let file = "musics.txt";
-    let results = read_music_data(file);
-    for result in results {
-        println!("{}", result);
+    for result in parse_music_file(file)? {
+        match result {
+            Ok(music) => println!("{}", music),
+            Err(e) => eprintln!("{}", e),
+        }
     }
+    Ok(())
 }
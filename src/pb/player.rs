@@ -11,9 +11,29 @@ pub struct CreateMusicRequest {
     #[prost(string, tag = "4")]
     pub author: ::prost::alloc::string::String,
 }
+/// 音乐信息
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MusicInfo {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub artist: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "6")]
+    pub duration: u64,
+}
 /// 添加音乐到数据库中的响应参数
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct PlayRequest {}
+pub struct PlayRequest {
+    /// 要播放的序号，从 1 开始；为 0 时播放当前歌曲
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PlayResponse {
     #[prost(bool, tag = "1")]
@@ -61,7 +81,11 @@ pub struct PreviousResponse {
     pub message: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct StopRequest {}
+pub struct StopRequest {
+    /// 为 true 时同时关闭播放器服务
+    #[prost(bool, tag = "1")]
+    pub quit: bool,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct StopResponse {
     #[prost(bool, tag = "1")]
@@ -80,8 +104,12 @@ pub struct ResumeResponse {
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SetModelRequest {
+    /// normal、shuffle、repeat 或 repeat_all
     #[prost(string, tag = "1")]
     pub model: ::prost::alloc::string::String,
+    /// 随机播放完一轮后是否重新洗牌继续，不设置时保持原设置
+    #[prost(bool, optional, tag = "2")]
+    pub shuffle_loop: ::core::option::Option<bool>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SetModelResponse {
@@ -103,11 +131,39 @@ pub struct AddPlaylistResponse {
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "5")]
+    pub duration: u64,
+    #[prost(uint32, tag = "6")]
+    pub index: u32,
+}
+/// 索引区间，索引从 1 开始（与 bpc page 显示一致），包含两端
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct IndexRange {
+    #[prost(uint32, tag = "1")]
+    pub start: u32,
+    #[prost(uint32, tag = "2")]
+    pub end: u32,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeletedRequest {
-    #[prost(string, tag = "1")]
-    pub bvid: ::prost::alloc::string::String,
+    #[prost(oneof = "deleted_request::Target", tags = "1, 2, 3")]
+    pub target: ::core::option::Option<deleted_request::Target>,
+}
+/// Nested message and enum types in `DeletedRequest`.
+pub mod deleted_request {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Target {
+        #[prost(string, tag = "1")]
+        Bvid(::prost::alloc::string::String),
+        #[prost(uint32, tag = "2")]
+        Index(u32),
+        #[prost(message, tag = "3")]
+        Range(super::IndexRange),
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeletedResponse {
@@ -115,6 +171,8 @@ pub struct DeletedResponse {
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub removed: u32,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetStateRequest {}
@@ -122,8 +180,29 @@ pub struct GetStateRequest {}
 pub struct GetStateResponse {
     #[prost(bool, tag = "1")]
     pub success: bool,
-    #[prost(string, tag = "2")]
-    pub message: ::prost::alloc::string::String,
+    #[prost(enumeration = "PlaybackState", tag = "3")]
+    pub state: i32,
+    /// 当前播放位置，单位为毫秒，未知时不设置
+    #[prost(uint64, optional, tag = "4")]
+    pub position_ms: ::core::option::Option<u64>,
+    /// 当前歌曲总时长，单位为毫秒，未知时不设置
+    #[prost(uint64, optional, tag = "5")]
+    pub duration_ms: ::core::option::Option<u64>,
+    #[prost(uint32, tag = "6")]
+    pub volume: u32,
+    #[prost(enumeration = "PlayMode", tag = "7")]
+    pub mode: i32,
+    /// 当前歌曲在播放列表中的序号，从 1 开始，没有当前歌曲时不设置
+    #[prost(uint32, optional, tag = "8")]
+    pub index: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "9")]
+    pub playlist_length: u32,
+    /// 当前歌曲信息
+    #[prost(message, optional, tag = "10")]
+    pub music: ::core::option::Option<MusicInfo>,
+    /// 当前音轨的音质，没有播放时不设置
+    #[prost(enumeration = "AudioQuality", optional, tag = "11")]
+    pub audio_quality: ::core::option::Option<i32>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ShowMusicPageInfoRequest {
@@ -157,6 +236,8 @@ pub struct SetVolumeResponse {
 pub struct SeekRequest {
     #[prost(int64, tag = "1")]
     pub position: i64,
+    #[prost(enumeration = "SeekMode", tag = "2")]
+    pub mode: i32,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SeekResponse {
@@ -164,6 +245,543 @@ pub struct SeekResponse {
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "3")]
+    pub position_ms: u64,
+    #[prost(uint64, tag = "4")]
+    pub duration_ms: u64,
+}
+/// 查找条件，为空的字段不参与匹配
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindRequest {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub owner: ::prost::alloc::string::String,
+    /// 最多返回多少条结果，为 0 时不限制
+    #[prost(uint32, tag = "5")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FoundMusic {
+    /// 在播放列表中的序号，从 1 开始
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub music: ::core::option::Option<MusicInfo>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub results: ::prost::alloc::vec::Vec<FoundMusic>,
+}
+/// 待播队列：独立于播放列表，优先于播放列表播放（单曲循环模式除外）
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueueNextRequest {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueueNextResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    /// 歌曲在待播队列中的序号，从 1 开始
+    #[prost(uint32, tag = "4")]
+    pub position: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueueLastRequest {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QueueLastResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    /// 歌曲在待播队列中的序号，从 1 开始
+    #[prost(uint32, tag = "4")]
+    pub position: u32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ClearQueueRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ClearQueueResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub cleared: u32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListQueueRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListQueueResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
+}
+/// 命名歌单：完整的音乐库作为默认歌单，name 为空时表示音乐库
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistInfo {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub track_count: u32,
+    /// 是否为当前使用的歌单
+    #[prost(bool, tag = "3")]
+    pub active: bool,
+    /// 是否为默认的音乐库
+    #[prost(bool, tag = "4")]
+    pub is_library: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreatePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreatePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub new_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddToPlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub bvid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddToPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveFromPlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub bvid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveFromPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwitchPlaylistRequest {
+    /// 要切换到的歌单，为空时切换回音乐库
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwitchPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub length: u32,
+    /// 切换后的当前歌曲序号，从 1 开始
+    #[prost(uint32, optional, tag = "4")]
+    pub index: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "5")]
+    pub music: ::core::option::Option<MusicInfo>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListPlaylistsRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPlaylistsResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub playlists: ::prost::alloc::vec::Vec<PlaylistInfo>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MoveTrackRequest {
+    #[prost(uint32, tag = "1")]
+    pub from: u32,
+    #[prost(uint32, tag = "2")]
+    pub to: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MoveTrackResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// 调整后当前歌曲的序号
+    #[prost(uint32, optional, tag = "3")]
+    pub index: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwapTracksRequest {
+    #[prost(uint32, tag = "1")]
+    pub first: u32,
+    #[prost(uint32, tag = "2")]
+    pub second: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwapTracksResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// 调整后当前歌曲的序号
+    #[prost(uint32, optional, tag = "3")]
+    pub index: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SortPlaylistRequest {
+    #[prost(enumeration = "SortKey", tag = "1")]
+    pub key: i32,
+    #[prost(bool, tag = "2")]
+    pub descending: bool,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SortPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// 调整后当前歌曲的序号
+    #[prost(uint32, optional, tag = "3")]
+    pub index: ::core::option::Option<u32>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LoginStatusRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LoginStatusResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// 登录凭据是否有效
+    #[prost(bool, tag = "3")]
+    pub logged_in: bool,
+    #[prost(string, tag = "4")]
+    pub uname: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "5")]
+    pub mid: u64,
+    /// 是否为大会员
+    #[prost(bool, tag = "6")]
+    pub vip: bool,
+}
+/// 更新服务端的登录凭据，没有设置的字段保持不变
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateCredentialsRequest {
+    #[prost(string, optional, tag = "1")]
+    pub sessdata: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "2")]
+    pub bili_jct: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag = "3")]
+    pub buvid3: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UpdateCredentialsResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// 更新后的凭据是否有效
+    #[prost(bool, tag = "3")]
+    pub logged_in: bool,
+    #[prost(string, tag = "4")]
+    pub uname: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeRequest {}
+/// 切换了当前歌曲
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TrackChanged {
+    /// 当前歌曲在播放列表中的序号，从 1 开始
+    #[prost(uint32, optional, tag = "1")]
+    pub index: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "2")]
+    pub music: ::core::option::Option<MusicInfo>,
+}
+/// 播放进度，播放中每秒推送一次
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PositionTick {
+    #[prost(uint64, optional, tag = "1")]
+    pub position_ms: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag = "2")]
+    pub duration_ms: ::core::option::Option<u64>,
+}
+/// 播放列表发生了变化
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistChanged {
+    #[prost(uint32, tag = "1")]
+    pub length: u32,
+}
+/// 播放器事件
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlayerEvent {
+    #[prost(oneof = "player_event::Event", tags = "1, 2, 3, 4, 5, 6, 7")]
+    pub event: ::core::option::Option<player_event::Event>,
+}
+/// Nested message and enum types in `PlayerEvent`.
+pub mod player_event {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "1")]
+        TrackChanged(super::TrackChanged),
+        #[prost(enumeration = "super::PlaybackState", tag = "2")]
+        StateChanged(i32),
+        #[prost(message, tag = "3")]
+        Position(super::PositionTick),
+        #[prost(uint32, tag = "4")]
+        VolumeChanged(u32),
+        #[prost(enumeration = "super::PlayMode", tag = "5")]
+        ModeChanged(i32),
+        #[prost(message, tag = "6")]
+        PlaylistChanged(super::PlaylistChanged),
+        #[prost(string, tag = "7")]
+        Error(::prost::alloc::string::String),
+    }
+}
+/// 播放状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlaybackState {
+    Idle = 0,
+    Ready = 1,
+    Playing = 2,
+    Paused = 3,
+    Ended = 4,
+    Error = 5,
+    Stopped = 6,
+}
+impl PlaybackState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Idle => "PLAYBACK_STATE_IDLE",
+            Self::Ready => "PLAYBACK_STATE_READY",
+            Self::Playing => "PLAYBACK_STATE_PLAYING",
+            Self::Paused => "PLAYBACK_STATE_PAUSED",
+            Self::Ended => "PLAYBACK_STATE_ENDED",
+            Self::Error => "PLAYBACK_STATE_ERROR",
+            Self::Stopped => "PLAYBACK_STATE_STOPPED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PLAYBACK_STATE_IDLE" => Some(Self::Idle),
+            "PLAYBACK_STATE_READY" => Some(Self::Ready),
+            "PLAYBACK_STATE_PLAYING" => Some(Self::Playing),
+            "PLAYBACK_STATE_PAUSED" => Some(Self::Paused),
+            "PLAYBACK_STATE_ENDED" => Some(Self::Ended),
+            "PLAYBACK_STATE_ERROR" => Some(Self::Error),
+            "PLAYBACK_STATE_STOPPED" => Some(Self::Stopped),
+            _ => None,
+        }
+    }
+}
+/// 播放模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlayMode {
+    Normal = 0,
+    Shuffle = 1,
+    Repeat = 2,
+    RepeatAll = 3,
+}
+impl PlayMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Normal => "PLAY_MODE_NORMAL",
+            Self::Shuffle => "PLAY_MODE_SHUFFLE",
+            Self::Repeat => "PLAY_MODE_REPEAT",
+            Self::RepeatAll => "PLAY_MODE_REPEAT_ALL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PLAY_MODE_NORMAL" => Some(Self::Normal),
+            "PLAY_MODE_SHUFFLE" => Some(Self::Shuffle),
+            "PLAY_MODE_REPEAT" => Some(Self::Repeat),
+            "PLAY_MODE_REPEAT_ALL" => Some(Self::RepeatAll),
+            _ => None,
+        }
+    }
+}
+/// 音质，对应 DASH 音轨 id
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AudioQuality {
+    Unspecified = 0,
+    /// 30216，64K
+    Standard = 1,
+    /// 30232，132K
+    High = 2,
+    /// 30280，192K
+    VeryHigh = 3,
+    /// 30250，杜比全景声
+    Dolby = 4,
+    /// 30251，Hi-Res 无损
+    HiRes = 5,
+}
+impl AudioQuality {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AUDIO_QUALITY_UNSPECIFIED",
+            Self::Standard => "AUDIO_QUALITY_STANDARD",
+            Self::High => "AUDIO_QUALITY_HIGH",
+            Self::VeryHigh => "AUDIO_QUALITY_VERY_HIGH",
+            Self::Dolby => "AUDIO_QUALITY_DOLBY",
+            Self::HiRes => "AUDIO_QUALITY_HI_RES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AUDIO_QUALITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "AUDIO_QUALITY_STANDARD" => Some(Self::Standard),
+            "AUDIO_QUALITY_HIGH" => Some(Self::High),
+            "AUDIO_QUALITY_VERY_HIGH" => Some(Self::VeryHigh),
+            "AUDIO_QUALITY_DOLBY" => Some(Self::Dolby),
+            "AUDIO_QUALITY_HI_RES" => Some(Self::HiRes),
+            _ => None,
+        }
+    }
+}
+/// 跳转方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SeekMode {
+    /// 跳转到绝对位置，position 单位为毫秒
+    Absolute = 0,
+    /// 相对当前位置跳转，position 单位为毫秒，可为负数
+    Relative = 1,
+    /// 按总时长的百分比跳转，position 取值 0-100
+    Percent = 2,
+}
+impl SeekMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Absolute => "SEEK_MODE_ABSOLUTE",
+            Self::Relative => "SEEK_MODE_RELATIVE",
+            Self::Percent => "SEEK_MODE_PERCENT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SEEK_MODE_ABSOLUTE" => Some(Self::Absolute),
+            "SEEK_MODE_RELATIVE" => Some(Self::Relative),
+            "SEEK_MODE_PERCENT" => Some(Self::Percent),
+            _ => None,
+        }
+    }
+}
+/// 调整播放列表顺序，序号都从 1 开始
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SortKey {
+    Title = 0,
+    Artist = 1,
+    Owner = 2,
+    Duration = 3,
+    Added = 4,
+}
+impl SortKey {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Title => "SORT_KEY_TITLE",
+            Self::Artist => "SORT_KEY_ARTIST",
+            Self::Owner => "SORT_KEY_OWNER",
+            Self::Duration => "SORT_KEY_DURATION",
+            Self::Added => "SORT_KEY_ADDED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SORT_KEY_TITLE" => Some(Self::Title),
+            "SORT_KEY_ARTIST" => Some(Self::Artist),
+            "SORT_KEY_OWNER" => Some(Self::Owner),
+            "SORT_KEY_DURATION" => Some(Self::Duration),
+            "SORT_KEY_ADDED" => Some(Self::Added),
+            _ => None,
+        }
+    }
 }
 /// Generated client implementations.
 pub mod player_service_client {
@@ -571,194 +1189,1374 @@ pub mod player_service_client {
             req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Seek"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod player_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with PlayerServiceServer.
-    #[async_trait]
-    pub trait PlayerService: std::marker::Send + std::marker::Sync + 'static {
-        async fn play(
-            &self,
-            request: tonic::Request<super::PlayRequest>,
-        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status>;
-        async fn play_bvid(
-            &self,
-            request: tonic::Request<super::PlayBvidRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::PlayBvidResponse>,
+        pub async fn find(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Find",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Find"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PlayerEvent>>,
             tonic::Status,
-        >;
-        async fn pause(
-            &self,
-            request: tonic::Request<super::PauseRequest>,
-        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status>;
-        async fn next(
-            &self,
-            request: tonic::Request<super::NextRequest>,
-        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status>;
-        async fn previous(
-            &self,
-            request: tonic::Request<super::PreviousRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "Subscribe"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn queue_next(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueueNextRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PreviousResponse>,
+            tonic::Response<super::QueueNextResponse>,
             tonic::Status,
-        >;
-        async fn stop(
-            &self,
-            request: tonic::Request<super::StopRequest>,
-        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status>;
-        async fn resume(
-            &self,
-            request: tonic::Request<super::ResumeRequest>,
-        ) -> std::result::Result<tonic::Response<super::ResumeResponse>, tonic::Status>;
-        async fn set_model(
-            &self,
-            request: tonic::Request<super::SetModelRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/QueueNext",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "QueueNext"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn queue_last(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueueLastRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetModelResponse>,
+            tonic::Response<super::QueueLastResponse>,
             tonic::Status,
-        >;
-        async fn add_playlist(
-            &self,
-            request: tonic::Request<super::AddPlaylistRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/QueueLast",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "QueueLast"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn clear_queue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ClearQueueRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AddPlaylistResponse>,
+            tonic::Response<super::ClearQueueResponse>,
             tonic::Status,
-        >;
-        async fn deleted(
-            &self,
-            request: tonic::Request<super::DeletedRequest>,
-        ) -> std::result::Result<tonic::Response<super::DeletedResponse>, tonic::Status>;
-        async fn get_state(
-            &self,
-            request: tonic::Request<super::GetStateRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ClearQueue",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ClearQueue"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_queue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListQueueRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetStateResponse>,
+            tonic::Response<super::ListQueueResponse>,
             tonic::Status,
-        >;
-        async fn show_music_page_info(
-            &self,
-            request: tonic::Request<super::ShowMusicPageInfoRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ListQueue",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ListQueue"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn create_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreatePlaylistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ShowMusicPageInfoResponse>,
+            tonic::Response<super::CreatePlaylistResponse>,
             tonic::Status,
-        >;
-        async fn set_volume(
-            &self,
-            request: tonic::Request<super::SetVolumeRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/CreatePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "CreatePlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn rename_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RenamePlaylistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetVolumeResponse>,
+            tonic::Response<super::RenamePlaylistResponse>,
             tonic::Status,
-        >;
-        async fn seek(
-            &self,
-            request: tonic::Request<super::SeekRequest>,
-        ) -> std::result::Result<tonic::Response<super::SeekResponse>, tonic::Status>;
-    }
-    /// service
-    #[derive(Debug)]
-    pub struct PlayerServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> PlayerServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/RenamePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "RenamePlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        pub async fn delete_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeletePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/DeletePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "DeletePlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        pub async fn add_to_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddToPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/AddToPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "AddToPlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        pub async fn remove_from_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveFromPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/RemoveFromPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "RemoveFromPlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+        pub async fn switch_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SwitchPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwitchPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SwitchPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SwitchPlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        pub async fn list_playlists(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPlaylistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPlaylistsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ListPlaylists",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ListPlaylists"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        pub async fn move_track(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MoveTrackRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MoveTrackResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/MoveTrack",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "MoveTrack"));
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for PlayerServiceServer<T>
-    where
-        T: PlayerService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
-    {
-        type Response = http::Response<tonic::body::Body>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        pub async fn swap_tracks(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::SwapTracksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwapTracksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SwapTracks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SwapTracks"));
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            match req.uri().path() {
-                "/player.PlayerService/Play" => {
+        pub async fn sort_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SortPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SortPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SortPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SortPlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn login_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoginStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/LoginStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "LoginStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn update_credentials(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateCredentialsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateCredentialsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/UpdateCredentials",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "UpdateCredentials"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod player_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PlayerServiceServer.
+    #[async_trait]
+    pub trait PlayerService: std::marker::Send + std::marker::Sync + 'static {
+        async fn play(
+            &self,
+            request: tonic::Request<super::PlayRequest>,
+        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status>;
+        async fn play_bvid(
+            &self,
+            request: tonic::Request<super::PlayBvidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PlayBvidResponse>,
+            tonic::Status,
+        >;
+        async fn pause(
+            &self,
+            request: tonic::Request<super::PauseRequest>,
+        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status>;
+        async fn next(
+            &self,
+            request: tonic::Request<super::NextRequest>,
+        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status>;
+        async fn previous(
+            &self,
+            request: tonic::Request<super::PreviousRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PreviousResponse>,
+            tonic::Status,
+        >;
+        async fn stop(
+            &self,
+            request: tonic::Request<super::StopRequest>,
+        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status>;
+        async fn resume(
+            &self,
+            request: tonic::Request<super::ResumeRequest>,
+        ) -> std::result::Result<tonic::Response<super::ResumeResponse>, tonic::Status>;
+        async fn set_model(
+            &self,
+            request: tonic::Request<super::SetModelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetModelResponse>,
+            tonic::Status,
+        >;
+        async fn add_playlist(
+            &self,
+            request: tonic::Request<super::AddPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn deleted(
+            &self,
+            request: tonic::Request<super::DeletedRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeletedResponse>, tonic::Status>;
+        async fn get_state(
+            &self,
+            request: tonic::Request<super::GetStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStateResponse>,
+            tonic::Status,
+        >;
+        async fn show_music_page_info(
+            &self,
+            request: tonic::Request<super::ShowMusicPageInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ShowMusicPageInfoResponse>,
+            tonic::Status,
+        >;
+        async fn set_volume(
+            &self,
+            request: tonic::Request<super::SetVolumeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetVolumeResponse>,
+            tonic::Status,
+        >;
+        async fn seek(
+            &self,
+            request: tonic::Request<super::SeekRequest>,
+        ) -> std::result::Result<tonic::Response<super::SeekResponse>, tonic::Status>;
+        async fn find(
+            &self,
+            request: tonic::Request<super::FindRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindResponse>, tonic::Status>;
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PlayerEvent, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+        async fn queue_next(
+            &self,
+            request: tonic::Request<super::QueueNextRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueueNextResponse>,
+            tonic::Status,
+        >;
+        async fn queue_last(
+            &self,
+            request: tonic::Request<super::QueueLastRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QueueLastResponse>,
+            tonic::Status,
+        >;
+        async fn clear_queue(
+            &self,
+            request: tonic::Request<super::ClearQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ClearQueueResponse>,
+            tonic::Status,
+        >;
+        async fn list_queue(
+            &self,
+            request: tonic::Request<super::ListQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListQueueResponse>,
+            tonic::Status,
+        >;
+        async fn create_playlist(
+            &self,
+            request: tonic::Request<super::CreatePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreatePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn rename_playlist(
+            &self,
+            request: tonic::Request<super::RenamePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenamePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn delete_playlist(
+            &self,
+            request: tonic::Request<super::DeletePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn add_to_playlist(
+            &self,
+            request: tonic::Request<super::AddToPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn remove_from_playlist(
+            &self,
+            request: tonic::Request<super::RemoveFromPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn switch_playlist(
+            &self,
+            request: tonic::Request<super::SwitchPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwitchPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn list_playlists(
+            &self,
+            request: tonic::Request<super::ListPlaylistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPlaylistsResponse>,
+            tonic::Status,
+        >;
+        async fn move_track(
+            &self,
+            request: tonic::Request<super::MoveTrackRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::MoveTrackResponse>,
+            tonic::Status,
+        >;
+        async fn swap_tracks(
+            &self,
+            request: tonic::Request<super::SwapTracksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwapTracksResponse>,
+            tonic::Status,
+        >;
+        async fn sort_playlist(
+            &self,
+            request: tonic::Request<super::SortPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SortPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn login_status(
+            &self,
+            request: tonic::Request<super::LoginStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        >;
+        async fn update_credentials(
+            &self,
+            request: tonic::Request<super::UpdateCredentialsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateCredentialsResponse>,
+            tonic::Status,
+        >;
+    }
+    /// service
+    #[derive(Debug)]
+    pub struct PlayerServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PlayerServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PlayerServiceServer<T>
+    where
+        T: PlayerService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/player.PlayerService/Play" => {
+                    #[allow(non_camel_case_types)]
+                    struct PlaySvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PlayRequest> for PlaySvc<T> {
+                        type Response = super::PlayResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PlayRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::play(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PlaySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/PlayBvid" => {
+                    #[allow(non_camel_case_types)]
+                    struct PlayBvidSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PlayBvidRequest>
+                    for PlayBvidSvc<T> {
+                        type Response = super::PlayBvidResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PlayBvidRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::play_bvid(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PlayBvidSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Pause" => {
+                    #[allow(non_camel_case_types)]
+                    struct PauseSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PauseRequest> for PauseSvc<T> {
+                        type Response = super::PauseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PauseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::pause(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PauseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Next" => {
+                    #[allow(non_camel_case_types)]
+                    struct NextSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::NextRequest> for NextSvc<T> {
+                        type Response = super::NextResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NextRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::next(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NextSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Previous" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviousSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PreviousRequest>
+                    for PreviousSvc<T> {
+                        type Response = super::PreviousResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PreviousRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::previous(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviousSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Stop" => {
+                    #[allow(non_camel_case_types)]
+                    struct StopSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::StopRequest> for StopSvc<T> {
+                        type Response = super::StopResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StopRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::stop(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StopSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Resume" => {
+                    #[allow(non_camel_case_types)]
+                    struct ResumeSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::ResumeRequest>
+                    for ResumeSvc<T> {
+                        type Response = super::ResumeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ResumeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::resume(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ResumeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetModel" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetModelSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetModelRequest>
+                    for SetModelSvc<T> {
+                        type Response = super::SetModelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetModelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_model(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetModelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/AddPlaylist" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddPlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::AddPlaylistRequest>
+                    for AddPlaylistSvc<T> {
+                        type Response = super::AddPlaylistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddPlaylistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::add_playlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddPlaylistSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Deleted" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeletedSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::DeletedRequest>
+                    for DeletedSvc<T> {
+                        type Response = super::DeletedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeletedRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::deleted(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeletedSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/GetState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStateSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::GetStateRequest>
+                    for GetStateSvc<T> {
+                        type Response = super::GetStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::get_state(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/ShowMusicPageInfo" => {
+                    #[allow(non_camel_case_types)]
+                    struct ShowMusicPageInfoSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::ShowMusicPageInfoRequest>
+                    for ShowMusicPageInfoSvc<T> {
+                        type Response = super::ShowMusicPageInfoResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ShowMusicPageInfoRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::show_music_page_info(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ShowMusicPageInfoSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetVolume" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetVolumeSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetVolumeRequest>
+                    for SetVolumeSvc<T> {
+                        type Response = super::SetVolumeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetVolumeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_volume(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetVolumeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Seek" => {
+                    #[allow(non_camel_case_types)]
+                    struct SeekSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SeekRequest> for SeekSvc<T> {
+                        type Response = super::SeekResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SeekRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::seek(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SeekSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Find" => {
                     #[allow(non_camel_case_types)]
-                    struct PlaySvc<T: PlayerService>(pub Arc<T>);
+                    struct FindSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PlayRequest> for PlaySvc<T> {
-                        type Response = super::PlayResponse;
+                    > tonic::server::UnaryService<super::FindRequest> for FindSvc<T> {
+                        type Response = super::FindResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PlayRequest>,
+                            request: tonic::Request<super::FindRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::play(&inner, request).await
+                                <T as PlayerService>::find(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -769,7 +2567,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PlaySvc(inner);
+                        let method = FindSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -785,25 +2583,71 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/PlayBvid" => {
+                "/player.PlayerService/Subscribe" => {
                     #[allow(non_camel_case_types)]
-                    struct PlayBvidSvc<T: PlayerService>(pub Arc<T>);
+                    struct SubscribeSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PlayBvidRequest>
-                    for PlayBvidSvc<T> {
-                        type Response = super::PlayBvidResponse;
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::PlayerEvent;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/QueueNext" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueueNextSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::QueueNextRequest>
+                    for QueueNextSvc<T> {
+                        type Response = super::QueueNextResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PlayBvidRequest>,
+                            request: tonic::Request<super::QueueNextRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::play_bvid(&inner, request).await
+                                <T as PlayerService>::queue_next(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -814,7 +2658,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PlayBvidSvc(inner);
+                        let method = QueueNextSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -830,24 +2674,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Pause" => {
+                "/player.PlayerService/QueueLast" => {
                     #[allow(non_camel_case_types)]
-                    struct PauseSvc<T: PlayerService>(pub Arc<T>);
+                    struct QueueLastSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PauseRequest> for PauseSvc<T> {
-                        type Response = super::PauseResponse;
+                    > tonic::server::UnaryService<super::QueueLastRequest>
+                    for QueueLastSvc<T> {
+                        type Response = super::QueueLastResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PauseRequest>,
+                            request: tonic::Request<super::QueueLastRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::pause(&inner, request).await
+                                <T as PlayerService>::queue_last(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -858,7 +2703,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PauseSvc(inner);
+                        let method = QueueLastSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -874,24 +2719,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Next" => {
+                "/player.PlayerService/ClearQueue" => {
                     #[allow(non_camel_case_types)]
-                    struct NextSvc<T: PlayerService>(pub Arc<T>);
+                    struct ClearQueueSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::NextRequest> for NextSvc<T> {
-                        type Response = super::NextResponse;
+                    > tonic::server::UnaryService<super::ClearQueueRequest>
+                    for ClearQueueSvc<T> {
+                        type Response = super::ClearQueueResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NextRequest>,
+                            request: tonic::Request<super::ClearQueueRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::next(&inner, request).await
+                                <T as PlayerService>::clear_queue(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -902,7 +2748,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = NextSvc(inner);
+                        let method = ClearQueueSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -918,25 +2764,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Previous" => {
+                "/player.PlayerService/ListQueue" => {
                     #[allow(non_camel_case_types)]
-                    struct PreviousSvc<T: PlayerService>(pub Arc<T>);
+                    struct ListQueueSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PreviousRequest>
-                    for PreviousSvc<T> {
-                        type Response = super::PreviousResponse;
+                    > tonic::server::UnaryService<super::ListQueueRequest>
+                    for ListQueueSvc<T> {
+                        type Response = super::ListQueueResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PreviousRequest>,
+                            request: tonic::Request<super::ListQueueRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::previous(&inner, request).await
+                                <T as PlayerService>::list_queue(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -947,7 +2793,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PreviousSvc(inner);
+                        let method = ListQueueSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -963,24 +2809,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Stop" => {
+                "/player.PlayerService/CreatePlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct StopSvc<T: PlayerService>(pub Arc<T>);
+                    struct CreatePlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::StopRequest> for StopSvc<T> {
-                        type Response = super::StopResponse;
+                    > tonic::server::UnaryService<super::CreatePlaylistRequest>
+                    for CreatePlaylistSvc<T> {
+                        type Response = super::CreatePlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StopRequest>,
+                            request: tonic::Request<super::CreatePlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::stop(&inner, request).await
+                                <T as PlayerService>::create_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -991,7 +2838,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = StopSvc(inner);
+                        let method = CreatePlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1007,25 +2854,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Resume" => {
+                "/player.PlayerService/RenamePlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct ResumeSvc<T: PlayerService>(pub Arc<T>);
+                    struct RenamePlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::ResumeRequest>
-                    for ResumeSvc<T> {
-                        type Response = super::ResumeResponse;
+                    > tonic::server::UnaryService<super::RenamePlaylistRequest>
+                    for RenamePlaylistSvc<T> {
+                        type Response = super::RenamePlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ResumeRequest>,
+                            request: tonic::Request<super::RenamePlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::resume(&inner, request).await
+                                <T as PlayerService>::rename_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1036,7 +2883,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ResumeSvc(inner);
+                        let method = RenamePlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1052,25 +2899,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/SetModel" => {
+                "/player.PlayerService/DeletePlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct SetModelSvc<T: PlayerService>(pub Arc<T>);
+                    struct DeletePlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::SetModelRequest>
-                    for SetModelSvc<T> {
-                        type Response = super::SetModelResponse;
+                    > tonic::server::UnaryService<super::DeletePlaylistRequest>
+                    for DeletePlaylistSvc<T> {
+                        type Response = super::DeletePlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetModelRequest>,
+                            request: tonic::Request<super::DeletePlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::set_model(&inner, request).await
+                                <T as PlayerService>::delete_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1081,7 +2928,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetModelSvc(inner);
+                        let method = DeletePlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1097,25 +2944,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/AddPlaylist" => {
+                "/player.PlayerService/AddToPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct AddPlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    struct AddToPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::AddPlaylistRequest>
-                    for AddPlaylistSvc<T> {
-                        type Response = super::AddPlaylistResponse;
+                    > tonic::server::UnaryService<super::AddToPlaylistRequest>
+                    for AddToPlaylistSvc<T> {
+                        type Response = super::AddToPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddPlaylistRequest>,
+                            request: tonic::Request<super::AddToPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::add_playlist(&inner, request).await
+                                <T as PlayerService>::add_to_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1126,7 +2973,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = AddPlaylistSvc(inner);
+                        let method = AddToPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1142,25 +2989,26 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Deleted" => {
+                "/player.PlayerService/RemoveFromPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct DeletedSvc<T: PlayerService>(pub Arc<T>);
+                    struct RemoveFromPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::DeletedRequest>
-                    for DeletedSvc<T> {
-                        type Response = super::DeletedResponse;
+                    > tonic::server::UnaryService<super::RemoveFromPlaylistRequest>
+                    for RemoveFromPlaylistSvc<T> {
+                        type Response = super::RemoveFromPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeletedRequest>,
+                            request: tonic::Request<super::RemoveFromPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::deleted(&inner, request).await
+                                <T as PlayerService>::remove_from_playlist(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1171,7 +3019,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeletedSvc(inner);
+                        let method = RemoveFromPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1187,25 +3035,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/GetState" => {
+                "/player.PlayerService/SwitchPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStateSvc<T: PlayerService>(pub Arc<T>);
+                    struct SwitchPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::GetStateRequest>
-                    for GetStateSvc<T> {
-                        type Response = super::GetStateResponse;
+                    > tonic::server::UnaryService<super::SwitchPlaylistRequest>
+                    for SwitchPlaylistSvc<T> {
+                        type Response = super::SwitchPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetStateRequest>,
+                            request: tonic::Request<super::SwitchPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::get_state(&inner, request).await
+                                <T as PlayerService>::switch_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1216,7 +3064,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetStateSvc(inner);
+                        let method = SwitchPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1232,26 +3080,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/ShowMusicPageInfo" => {
+                "/player.PlayerService/ListPlaylists" => {
                     #[allow(non_camel_case_types)]
-                    struct ShowMusicPageInfoSvc<T: PlayerService>(pub Arc<T>);
+                    struct ListPlaylistsSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::ShowMusicPageInfoRequest>
-                    for ShowMusicPageInfoSvc<T> {
-                        type Response = super::ShowMusicPageInfoResponse;
+                    > tonic::server::UnaryService<super::ListPlaylistsRequest>
+                    for ListPlaylistsSvc<T> {
+                        type Response = super::ListPlaylistsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ShowMusicPageInfoRequest>,
+                            request: tonic::Request<super::ListPlaylistsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::show_music_page_info(&inner, request)
-                                    .await
+                                <T as PlayerService>::list_playlists(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1262,7 +3109,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ShowMusicPageInfoSvc(inner);
+                        let method = ListPlaylistsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1278,25 +3125,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/SetVolume" => {
+                "/player.PlayerService/MoveTrack" => {
                     #[allow(non_camel_case_types)]
-                    struct SetVolumeSvc<T: PlayerService>(pub Arc<T>);
+                    struct MoveTrackSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::SetVolumeRequest>
-                    for SetVolumeSvc<T> {
-                        type Response = super::SetVolumeResponse;
+                    > tonic::server::UnaryService<super::MoveTrackRequest>
+                    for MoveTrackSvc<T> {
+                        type Response = super::MoveTrackResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetVolumeRequest>,
+                            request: tonic::Request<super::MoveTrackRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::set_volume(&inner, request).await
+                                <T as PlayerService>::move_track(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1307,7 +3154,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetVolumeSvc(inner);
+                        let method = MoveTrackSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1323,24 +3170,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Seek" => {
+                "/player.PlayerService/SwapTracks" => {
                     #[allow(non_camel_case_types)]
-                    struct SeekSvc<T: PlayerService>(pub Arc<T>);
+                    struct SwapTracksSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::SeekRequest> for SeekSvc<T> {
-                        type Response = super::SeekResponse;
+                    > tonic::server::UnaryService<super::SwapTracksRequest>
+                    for SwapTracksSvc<T> {
+                        type Response = super::SwapTracksResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SeekRequest>,
+                            request: tonic::Request<super::SwapTracksRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::seek(&inner, request).await
+                                <T as PlayerService>::swap_tracks(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1351,7 +3199,143 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SeekSvc(inner);
+                        let method = SwapTracksSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SortPlaylist" => {
+                    #[allow(non_camel_case_types)]
+                    struct SortPlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SortPlaylistRequest>
+                    for SortPlaylistSvc<T> {
+                        type Response = super::SortPlaylistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SortPlaylistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::sort_playlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SortPlaylistSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/LoginStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct LoginStatusSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::LoginStatusRequest>
+                    for LoginStatusSvc<T> {
+                        type Response = super::LoginStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LoginStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::login_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LoginStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/UpdateCredentials" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpdateCredentialsSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::UpdateCredentialsRequest>
+                    for UpdateCredentialsSvc<T> {
+                        type Response = super::UpdateCredentialsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateCredentialsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::update_credentials(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpdateCredentialsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
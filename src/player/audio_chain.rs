@@ -9,12 +9,14 @@ use crate::errors::{PlayerError, PlayerResult};
 pub struct AudioChainBuilder {
     pub url: String,
     pub volume: f64,
+    pub cookie: Option<String>,
 }
 impl Default for AudioChainBuilder {
     fn default() -> Self {
         Self {
             url: String::new(),
             volume: 1.0,
+            cookie: None,
         }
     }
 }
@@ -37,6 +39,12 @@ impl AudioChainBuilder {
         self
     }
 
+    /// 设置请求音频时携带的登录 Cookie
+    pub fn with_cookie(mut self, cookie: Option<&str>) -> Self {
+        self.cookie = cookie.map(str::to_string);
+        self
+    }
+
     /// 构建音频 pipeline（返回 Pipeline + source 元素，用于后续控制）
     pub fn build(self) -> PlayerResult<(gstreamer::Pipeline, gstreamer::Element)> {
         if self.url.is_empty() {
@@ -126,6 +134,9 @@ impl AudioChainBuilder {
             "Mozilla/5.0 BiliDroid/..* (bbcallen@gmail.com)",
         );
         headers.set("Referer", "https://www.bilibili.com");
+        if let Some(cookie) = &self.cookie {
+            headers.set("Cookie", cookie.as_str());
+        }
         headers
     }
 }
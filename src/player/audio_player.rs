@@ -1,16 +1,34 @@
-use std::{future::pending, sync::Arc};
+use std::{future::pending, ops::Range, pin::Pin, sync::Arc, time::Duration};
 
 use tokio::{
     select,
-    sync::{Mutex, mpsc},
+    sync::{Mutex, broadcast, mpsc},
+    time::Sleep,
 };
 
 use crate::{
     errors::{PlayerError, PlayerResult},
-    pb::ShowMusicPageInfoResponse,
+    fetch::{
+        cdn::CdnHosts,
+        credentials::CredentialStore,
+        stream::{AudioPreference, StreamSelector},
+    },
+    library::{
+        playlists::{PlaylistRecord, PlaylistRepository},
+        repository::MusicRepository,
+        session::{SessionRecord, SessionRepository},
+    },
+    pb::{DeletedRequest, PlaylistInfo, ShowMusicPageInfoResponse, deleted_request::Target},
     player::{
-        command::PlayerCommand, music_data::read_music_data, play_mode::PlayMode,
-        playback::PlaybackManager, playlist::PlaylistManager, state::PlayerState,
+        command::PlayerCommand,
+        event::{EosEvent, PlayerEvent},
+        model::MusicInfo,
+        play_mode::PlayMode,
+        playback::{PlaybackManager, PlaybackState, SeekTarget},
+        playlist::PlaylistManager,
+        queue::QueuePosition,
+        reorder::Reorder,
+        state::PlayerState,
         volume::VolumeManager,
     },
 };
@@ -19,27 +37,63 @@ pub struct AudioPlayer {
     pub playback_manager: Arc<Mutex<PlaybackManager>>, // 播放管理
     pub volume_manager: Arc<VolumeManager>,            // 音量管理
     pub playlist_manager: Arc<PlaylistManager>,        // 播放列表管理
-    pub client: Arc<reqwest::Client>,                  // HTTP客户端
-    eos_receiver: Mutex<Option<mpsc::Receiver<()>>>,   // EOS事件接收器
+    pub client: Arc<reqwest::Client>,                  // HTTP客户端，与 GStreamer 共用登录 Cookie
+    pub credentials: CredentialStore,                  // 登录凭据
+    pub library: MusicRepository,                      // 音乐库
+    pub playlists: PlaylistRepository,                 // 命名歌单
+    active_playlist: Mutex<Option<PlaylistRecord>>,    // 当前使用的歌单，None 表示音乐库
+    library_last_bvid: Mutex<Option<String>>,          // 音乐库上次播放到的歌曲，随会话保存
+    session: SessionRepository,                        // 会话存储，重启后恢复播放状态
+    saved_session: Mutex<Option<SessionRecord>>,       // 最近一次保存的会话，没有变化时不重复写入
+    pub event_sender: broadcast::Sender<PlayerEvent>,  // 事件发送器
+    eos_receiver: Mutex<Option<mpsc::Receiver<EosEvent>>>, // EOS事件接收器
     command_receiver: mpsc::Receiver<PlayerCommand>,   // 命令接收器
 }
-// pub state_sender: broadcast::Sender<PlayerState>, // 状态发送器
+/// 当前歌曲播放出错后的重试状态
+#[derive(Default)]
+struct EosRetry {
+    bvid: Option<String>,              // 正在重试的歌曲
+    attempts: u32,                     // 已重试的次数
+    deadline: Option<Pin<Box<Sleep>>>, // 下一次重试的时间，到时间前命令照常处理
+}
+impl EosRetry {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+/// 事件通道容量，订阅者处理过慢时会丢弃最旧的事件
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+/// 播放进度推送间隔
+const POSITION_TICK_INTERVAL: Duration = Duration::from_secs(1);
+/// 同一首歌播放出错时最多重试的次数
+const MAX_EOS_RETRIES: u32 = 3;
+/// 重试前的等待时间，随重试次数递增
+const EOS_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// 播放时定期保存播放进度的间隔
+const SESSION_SAVE_INTERVAL: Duration = Duration::from_secs(10);
+/// 关闭播放器时淡出的时长
+const FADE_OUT_DURATION: Duration = Duration::from_millis(800);
+/// 恢复播放进度时等待管道就绪的最大次数
+const RESUME_SEEK_ATTEMPTS: u32 = 20;
+/// 每次等待管道就绪的时间
+const RESUME_SEEK_DELAY: Duration = Duration::from_millis(250);
 impl AudioPlayer {
-    pub async fn new(file: &str) -> PlayerResult<(Self, mpsc::Sender<PlayerCommand>)> {
+    pub async fn new(
+        library: MusicRepository,
+        credentials: CredentialStore,
+    ) -> PlayerResult<(Self, mpsc::Sender<PlayerCommand>)> {
         // 1. 初始化 GStreamer 和 Pipeline
         gstreamer::init().map_err(|e| PlayerError::GstInit(e.to_string()))?;
         let pipeline = gstreamer::Pipeline::new();
 
-        // 2. 初始化播放列表
+        // 2. 从音乐库初始化播放列表
         let playlist_manager = Arc::new(PlaylistManager::new());
-        let music_data = read_music_data(file);
-        // let music_data = get_music_data();
-        for music in music_data {
-            playlist_manager.add_music(music).await;
+        for record in library.list_all().await? {
+            playlist_manager.add_music(record.into()).await;
         }
 
         // 3. 创建发送播放结束的信号通道
-        let (eos_sender, eos_receiver) = mpsc::channel::<()>(1);
+        let (eos_sender, eos_receiver) = mpsc::channel::<EosEvent>(1);
         // 4. 创建播放管理器
         let playback_manager = PlaybackManager::new(pipeline, Some(eos_sender));
 
@@ -48,12 +102,24 @@ impl AudioPlayer {
 
         // 6. 创建发送播放结束的信号通道
         let (cmd_sender, cmd_receiver) = mpsc::channel::<PlayerCommand>(1);
-        // 7. 创建播放器实例
+        // 7. 创建播放器事件的广播通道、歌单仓储和会话仓储
+        let playlists = PlaylistRepository::new(library.pool().clone());
+        let session = SessionRepository::new(library.pool().clone());
+        let (event_sender, _) = broadcast::channel::<PlayerEvent>(EVENT_CHANNEL_CAPACITY);
+        // 8. 创建播放器实例
         let player = Self {
             playback_manager: Arc::new(Mutex::new(playback_manager)),
             volume_manager,
-            client: Arc::new(reqwest::Client::new()),
+            client: Arc::new(credentials.client()?),
+            credentials,
+            library,
+            playlists,
+            active_playlist: Mutex::new(None),
+            library_last_bvid: Mutex::new(None),
+            session,
+            saved_session: Mutex::new(None),
             playlist_manager,
+            event_sender,
             eos_receiver: Mutex::new(Some(eos_receiver)),
             command_receiver: cmd_receiver,
         };
@@ -75,76 +141,706 @@ impl AudioPlayer {
             play_mode: playlist_manager.get_play_mode().await,
             playlist_length: playlist_manager.get_playlist_len().await,
             current_index: playlist_manager.get_current_index().await,
+            audio_quality: playback_manager.get_audio_quality().await,
+        }
+    }
+    /// 订阅播放器事件
+    pub fn subscribe(&self) -> broadcast::Receiver<PlayerEvent> {
+        self.event_sender.subscribe()
+    }
+    /// 广播事件，没有订阅者时直接丢弃
+    fn emit(&self, event: PlayerEvent) {
+        let _ = self.event_sender.send(event);
+    }
+    /// 对比上一次的状态，广播发生变化的部分
+    async fn emit_changes(&self, last_state: &mut Option<PlayerState>) {
+        let state = self.get_current_state().await;
+        for event in PlayerEvent::diff(last_state.as_ref(), &state) {
+            self.emit(event);
         }
+        *last_state = Some(state);
     }
     /// 运行播放器
     pub async fn run(&mut self) -> PlayerResult<()> {
         // 获取 EOS 接收器
         let mut eos_receiver = self.eos_receiver.lock().await.take();
+        // 定时推送播放进度
+        let mut ticker = tokio::time::interval(POSITION_TICK_INTERVAL);
+        // 定时保存播放进度
+        let mut session_ticker = tokio::time::interval(SESSION_SAVE_INTERVAL);
+        let mut last_state = Some(self.get_current_state().await);
+        // 当前歌曲的重试状态
+        let mut retry = EosRetry::default();
         // 监听通道信号变化
         loop {
             select! {
                 cmd = self.command_receiver.recv() => {
                     if let Some(command) = cmd {
-                        self.handle_command(command).await?;
+                        // 手动控制播放后取消等待中的重试
+                        if matches!(
+                            command,
+                            PlayerCommand::Play
+                                | PlayerCommand::PlayIndex { .. }
+                                | PlayerCommand::PlayBvid(_)
+                                | PlayerCommand::Pause
+                                | PlayerCommand::Next
+                                | PlayerCommand::Previous
+                                | PlayerCommand::Stop
+                                | PlayerCommand::Resume
+                        ) {
+                            retry.reset();
+                        }
+                        let is_query = command.is_query();
+                        // 命令出错时只记录并通知订阅者，不退出播放器
+                        if let Err(e) = self.handle_command(command).await {
+                            tracing::error!("Failed to handle command: {}", e);
+                            self.emit(PlayerEvent::Error(e.to_string()));
+                        }
+                        if !is_query {
+                            self.save_session_logged(false).await;
+                        }
                     } else {
-                        break; // sender dropped
+                        break; // sender dropped，服务正在关闭
                     }
                 }
-                _ = async {
+                _ = ticker.tick() => {
+                    let playback = self.playback_manager.lock().await;
+                    if playback.get_playback_state().await == PlaybackState::Playing {
+                        self.emit(PlayerEvent::Position {
+                            position: playback.get_current_position().await,
+                            duration: playback.get_duration().await,
+                        });
+                    }
+                }
+                _ = session_ticker.tick() => {
+                    let state = self.playback_manager.lock().await.get_playback_state().await;
+                    if state == PlaybackState::Playing {
+                        self.save_session_logged(true).await;
+                    }
+                }
+                event = async {
                     if let Some(ref mut r) = eos_receiver {
                         r.recv().await
                     } else {
-                        pending::<Option<()>>().await
+                        pending::<Option<EosEvent>>().await
                     }
                 }, if eos_receiver.is_some() => {
-                    println!("[EOS] Playing next track...");
-                    let play_mode = self.playlist_manager.get_play_mode().await;
-                    if play_mode == PlayMode::Repeat {
-                        if let Some(music) = self.playlist_manager.get_current_music().await {
-                            let client = self.client.clone();
-                            let mut playback = self.playback_manager.lock().await;
-                            let volume = self.volume_manager.get_gstreamer_volume();
-                            playback.play_music(&client, &music, volume).await?;
-                        }
+                    if let Some(event) = event
+                        && let Err(e) = self.handle_eos_event(event, &mut retry).await
+                    {
+                        tracing::error!("Failed to handle end of track: {}", e);
+                        self.emit(PlayerEvent::Error(e.to_string()));
+                    }
+                    // 播放结束后可能已经切到下一首
+                    self.save_session_logged(false).await;
+                }
+                _ = async {
+                    if let Some(deadline) = retry.deadline.as_mut() {
+                        deadline.await
                     } else {
-                        // 触发下一首逻辑（内联，不走 command channel）
-                        if self.playlist_manager.move_to_next().await?
-                            && let Some(music) = self.playlist_manager.get_current_music().await {
-                                let client = self.client.clone();
-                                let mut playback = self.playback_manager.lock().await;
-                                let volume = self.volume_manager.get_gstreamer_volume();
-                                playback.play_music(&client, &music, volume).await?;
-                            }
+                        pending::<()>().await
+                    }
+                }, if retry.deadline.is_some() => {
+                    retry.deadline = None;
+                    tracing::warn!(
+                        "Retrying current track ({}/{})",
+                        retry.attempts,
+                        MAX_EOS_RETRIES
+                    );
+                    // play_current 会重新请求播放地址，过期的地址也能恢复
+                    if let Err(e) = self.play_current().await {
+                        tracing::warn!("Retry failed: {}", e);
+                        if let Err(e) = self.retry_or_skip(&mut retry).await {
+                            tracing::error!("Failed to handle end of track: {}", e);
+                            self.emit(PlayerEvent::Error(e.to_string()));
+                        }
+                        self.save_session_logged(false).await;
                     }
                 }
             }
+            // 切换到其他歌曲后重新计算重试次数
+            if retry.bvid.is_some()
+                && self
+                    .playlist_manager
+                    .get_current_music()
+                    .await
+                    .map(|m| m.bvid)
+                    != retry.bvid
+            {
+                retry.reset();
+            }
+            self.emit_changes(&mut last_state).await;
+        }
+        self.shutdown().await
+    }
+    /// 关闭播放器：保存会话和歌单位置，淡出后停止播放并把管道置为 Null
+    async fn shutdown(&self) -> PlayerResult<()> {
+        // 先记下歌单位置再保存会话，停止后就拿不到播放进度了
+        if let Err(e) = self.remember_position().await {
+            tracing::error!("Failed to save playlist position: {}", e);
         }
+        self.save_session_logged(true).await;
+        let mut playback = self.playback_manager.lock().await;
+        if playback.get_playback_state().await == PlaybackState::Playing {
+            self.volume_manager
+                .fade_out(playback.get_pipeline(), FADE_OUT_DURATION)
+                .await;
+        }
+        playback.stop().await?;
+        tracing::info!("Player shut down");
         Ok(())
     }
+    /// 收集需要保存的会话状态
+    async fn current_session(&self) -> SessionRecord {
+        let position_ms = {
+            let playback = self.playback_manager.lock().await;
+            match playback.get_playback_state().await {
+                // 正在播放待播队列中的歌曲时，进度不属于播放列表的当前歌曲
+                PlaybackState::Playing | PlaybackState::Paused
+                    if !self.playlist_manager.is_playing_queued().await =>
+                {
+                    playback
+                        .get_current_position()
+                        .await
+                        .map_or(0, |position| position.mseconds() as i64)
+                }
+                _ => 0,
+            }
+        }; // 🔓 playback_manager 锁释放
+        SessionRecord {
+            playlist_id: self.active_playlist.lock().await.as_ref().map(|p| p.id),
+            current_bvid: self
+                .playlist_manager
+                .get_cursor_music()
+                .await
+                .map(|music| music.bvid),
+            play_mode: self
+                .playlist_manager
+                .get_play_mode()
+                .await
+                .as_str()
+                .to_string(),
+            shuffle_loop: self.playlist_manager.get_shuffle_loop().await,
+            volume: self.volume_manager.get_volume_percentage() as i64,
+            position_ms,
+            library_bvid: self.library_last_bvid.lock().await.clone(),
+        }
+    }
+    /// 保存会话，with_position 为 false 时只有播放进度以外的状态变化了才写入
+    pub async fn save_session(&self, with_position: bool) -> PlayerResult<()> {
+        let session = self.current_session().await;
+        let mut saved = self.saved_session.lock().await;
+        let unchanged = match saved.as_ref() {
+            Some(saved) if with_position => *saved == session,
+            // 忽略播放进度的变化
+            Some(saved) => {
+                *saved
+                    == SessionRecord {
+                        position_ms: saved.position_ms,
+                        ..session.clone()
+                    }
+            }
+            None => false,
+        };
+        if !unchanged {
+            self.session.save(&session).await?;
+            *saved = Some(session);
+        }
+        Ok(())
+    }
+    /// 保存会话，失败时只记录日志，不影响播放
+    async fn save_session_logged(&self, with_position: bool) {
+        if let Err(e) = self.save_session(with_position).await {
+            tracing::error!("Failed to save session: {}", e);
+        }
+    }
+    /// 设置启动时的默认音量、播放模式和音质偏好，之后恢复的会话会覆盖音量和播放模式
+    pub async fn apply_defaults(
+        &self,
+        volume: u32,
+        mode: PlayMode,
+        audio: AudioPreference,
+    ) -> PlayerResult<()> {
+        let pipeline = {
+            let mut playback = self.playback_manager.lock().await;
+            playback.stream_selector = StreamSelector::new(audio);
+            playback.get_pipeline().clone()
+        };
+        self.volume_manager.set_volume(&pipeline, volume)?;
+        self.playlist_manager.set_play_mode(mode).await;
+        Ok(())
+    }
+    /// 设置备选的音频 CDN 域名，原地址都不可用时替换域名重试
+    pub async fn set_cdn_hosts(&self, hosts: Vec<String>) {
+        self.playback_manager.lock().await.cdn_hosts = CdnHosts::new(hosts);
+    }
+    /// 恢复上次保存的会话：歌单、当前歌曲、播放模式和音量
+    ///
+    /// resume 为 true 时播放当前歌曲并跳转到保存的位置。
+    pub async fn restore_session(&self, resume: bool) -> PlayerResult<()> {
+        let Some(session) = self.session.load().await? else {
+            return Ok(());
+        };
+        if let Some(id) = session.playlist_id
+            && let Some(playlist) = self.playlists.find_by_id(id).await?
+        {
+            self.switch_playlist(Some(playlist.name)).await?;
+        }
+        // 切换歌单时会记下启动时音乐库的位置，用保存的位置覆盖
+        *self.library_last_bvid.lock().await = session.library_bvid.clone();
+        if let Some(bvid) = &session.current_bvid
+            && let Some(index) = self.playlist_manager.find_music_index(bvid).await
+        {
+            self.playlist_manager.set_current_index(index).await?;
+        }
+        // 恢复的位置不算作播放历史
+        self.playlist_manager.history.lock().await.clear();
+        if let Some(mode) = PlayMode::from_string(&session.play_mode) {
+            self.playlist_manager.set_play_mode(mode).await;
+        }
+        self.playlist_manager
+            .set_shuffle_loop(session.shuffle_loop)
+            .await;
+        {
+            let playback = self.playback_manager.lock().await;
+            let pipeline = playback.get_pipeline().clone();
+            self.volume_manager
+                .set_volume(&pipeline, session.volume as u32)?;
+        } // 🔓 playback_manager 锁释放
+        tracing::info!(
+            "Restored session: {:?} at {}ms",
+            session.current_bvid,
+            session.position_ms
+        );
+        *self.saved_session.lock().await = Some(session.clone());
+
+        if resume && self.playlist_manager.get_current_music().await.is_some() {
+            self.play_current().await?;
+            if session.position_ms > 0 {
+                let position = gstreamer::ClockTime::from_mseconds(session.position_ms as u64);
+                self.seek_after_start(position).await;
+            }
+        }
+        Ok(())
+    }
+    /// 歌曲刚开始播放时管道还没有就绪，等到能获取总时长后再跳转
+    async fn seek_after_start(&self, position: gstreamer::ClockTime) {
+        for _ in 0..RESUME_SEEK_ATTEMPTS {
+            tokio::time::sleep(RESUME_SEEK_DELAY).await;
+            let playback = self.playback_manager.lock().await;
+            if playback.get_duration().await.is_none() {
+                continue;
+            }
+            match playback.seek_to(SeekTarget::Absolute(position)).await {
+                Ok(_) => {
+                    tracing::info!("Resumed playback at {}ms", position.mseconds());
+                    return;
+                }
+                Err(e) => tracing::debug!("Seek to saved position failed: {}", e),
+            }
+        }
+        tracing::warn!("Failed to resume playback at {}ms", position.mseconds());
+    }
+    /// 处理播放结束事件：正常结束时按播放模式继续，可重试的错误重新获取地址播放，其他错误跳过当前歌曲
+    async fn handle_eos_event(&self, event: EosEvent, retry: &mut EosRetry) -> PlayerResult<()> {
+        tracing::info!("[EOS] {:?}", event);
+        if let Some(error) = event.to_error_message() {
+            self.emit(PlayerEvent::Error(error));
+        }
+        match event {
+            EosEvent::NormalEnd => {
+                retry.reset();
+                self.play_after_eos().await
+            }
+            EosEvent::Interrupted => Ok(()),
+            EosEvent::PlaylistEnd => self.playback_manager.lock().await.finish().await,
+            event if event.should_retry() => self.retry_or_skip(retry).await,
+            // 无法恢复的错误或主动跳过
+            _ => {
+                retry.reset();
+                self.skip_track().await
+            }
+        }
+    }
+    /// 安排稍后重试当前歌曲，由 run 中的定时分支执行；重试次数用完时跳过
+    async fn retry_or_skip(&self, retry: &mut EosRetry) -> PlayerResult<()> {
+        let bvid = self
+            .playlist_manager
+            .get_current_music()
+            .await
+            .map(|music| music.bvid);
+        if retry.bvid != bvid {
+            retry.reset();
+            retry.bvid = bvid;
+        }
+        if retry.attempts < MAX_EOS_RETRIES {
+            retry.attempts += 1;
+            retry.deadline = Some(Box::pin(tokio::time::sleep(
+                EOS_RETRY_DELAY * retry.attempts,
+            )));
+            return Ok(());
+        }
+        retry.reset();
+        self.skip_track().await
+    }
+    /// 当前歌曲播放结束后，按播放模式继续播放
+    async fn play_after_eos(&self) -> PlayerResult<()> {
+        let play_mode = self.playlist_manager.get_play_mode().await;
+        if play_mode == PlayMode::Repeat {
+            return self.play_current().await;
+        }
+        // 触发下一首逻辑（内联，不走 command channel）
+        self.play_next().await
+    }
+    /// 播放下一首，已经到达列表末尾时停止并把状态设为播放结束
+    async fn play_next(&self) -> PlayerResult<()> {
+        if self.playlist_manager.move_to_next().await? {
+            self.play_current().await
+        } else {
+            tracing::info!("Reached the end of playlist");
+            self.playback_manager.lock().await.finish().await
+        }
+    }
+    /// 跳过无法播放的歌曲，下一首也无法播放时继续往后找，整个列表都失败时停止
+    async fn skip_track(&self) -> PlayerResult<()> {
+        let len = self.playlist_manager.get_playlist_len().await;
+        for _ in 0..len {
+            if !self.playlist_manager.move_to_next().await? {
+                break;
+            }
+            match self.play_current().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!("Skipped unplayable track: {}", e);
+                    self.emit(PlayerEvent::Error(e.to_string()));
+                }
+            }
+        }
+        self.playback_manager.lock().await.finish().await
+    }
+    /// 播放播放列表中的当前歌曲
+    async fn play_current(&self) -> PlayerResult<()> {
+        if let Some(music) = self.playlist_manager.get_current_music().await {
+            let client = self.client.clone();
+            let mut playback = self.playback_manager.lock().await;
+            let volume = self.volume_manager.get_gstreamer_volume();
+            playback
+                .play_music(&client, &music, volume, &self.credentials)
+                .await?;
+        }
+        Ok(())
+    }
+    /// 跳转到播放列表中指定索引的歌曲并播放
+    async fn play_index(&self, index: usize) -> PlayerResult<MusicInfo> {
+        self.playlist_manager.set_current_index(index).await?;
+        self.play_current().await?;
+        self.playlist_manager
+            .get_current_music()
+            .await
+            .ok_or_else(|| PlayerError::InvalidIndex(index.to_string()))
+    }
+    /// 把歌曲写入音乐库并追加到播放列表，返回歌曲在列表中的索引
+    async fn add_to_playlist(&self, music: MusicInfo) -> PlayerResult<(usize, MusicInfo)> {
+        if self.playlist_manager.is_in_playlist(&music.bvid).await {
+            return Err(PlayerError::AlreadyExists(format!(
+                "{} is already in playlist",
+                music.bvid
+            )));
+        }
+        let music_id = self.library.upsert(&music).await?;
+        // 当前使用命名歌单时同时加入该歌单
+        if let Some(playlist) = self.active_playlist.lock().await.as_ref() {
+            match self.playlists.add_music(playlist.id, music_id).await {
+                Ok(()) | Err(PlayerError::AlreadyExists(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.playlist_manager.add_music(music.clone()).await;
+        let index = self.playlist_manager.get_playlist_len().await - 1;
+        tracing::info!("Added to playlist: {}", music);
+        Ok((index, music))
+    }
+    /// 把歌曲加入待播队列，播放列表中已有该歌曲时使用播放列表中的信息，不会写入音乐库
+    async fn queue_music(
+        &self,
+        music: MusicInfo,
+        position: QueuePosition,
+    ) -> PlayerResult<(usize, MusicInfo)> {
+        let music = match self.playlist_manager.find_music_index(&music.bvid).await {
+            Some(index) => self
+                .playlist_manager
+                .get_playlist_info(index, index + 1)
+                .await
+                .pop()
+                .ok_or_else(|| PlayerError::InvalidIndex(index.to_string()))?,
+            None => music,
+        };
+        let index = self
+            .playlist_manager
+            .queue_music(music.clone(), position)
+            .await;
+        tracing::info!("Queued {:?}: {}", position, music);
+        Ok((index, music))
+    }
+    /// 按 bvid、索引或索引区间从播放列表中删除歌曲，返回被删除的歌曲
+    ///
+    /// 正在播放的歌曲被删除时，按播放模式切到下一首；没有下一首时停止播放。
+    async fn delete_from_playlist(&self, request: DeletedRequest) -> PlayerResult<Vec<MusicInfo>> {
+        let len = self.playlist_manager.get_playlist_len().await;
+        // 把删除目标转换为从 0 开始的索引区间
+        let range = match request.target {
+            Some(Target::Bvid(bvid)) => {
+                let index = self
+                    .playlist_manager
+                    .find_music_index(bvid.trim())
+                    .await
+                    .ok_or_else(|| PlayerError::NotFound(format!("{} is not in playlist", bvid)))?;
+                index..index + 1
+            }
+            Some(Target::Index(index)) => Self::index_range(index, index, len)?,
+            Some(Target::Range(range)) => Self::index_range(range.start, range.end, len)?,
+            None => return Err(PlayerError::InvalidArgument("Missing delete target".into())),
+        };
+        // 当前歌曲要被删除时，先按播放模式找到区间外的下一首
+        // 正在播放待播队列的歌曲时不受影响
+        let removing_current = !self.playlist_manager.is_playing_queued().await
+            && self
+                .playlist_manager
+                .get_current_index()
+                .await
+                .is_some_and(|index| range.contains(&index));
+        // 从播放列表中删除，使用命名歌单时同时从该歌单中移除，音乐库中的歌曲始终保留
+        let (removed, has_next) = if removing_current {
+            self.playlist_manager.remove_current_range(range).await?
+        } else {
+            (self.playlist_manager.remove_range(range).await?, false)
+        };
+        if let Some(playlist) = self.active_playlist.lock().await.clone() {
+            for music in &removed {
+                self.playlists
+                    .remove_music(playlist.id, &music.bvid)
+                    .await?;
+            }
+        }
+        tracing::info!("Removed {} musics from playlist", removed.len());
+        if removing_current {
+            let state = self
+                .playback_manager
+                .lock()
+                .await
+                .get_playback_state()
+                .await;
+            let active = matches!(state, PlaybackState::Playing | PlaybackState::Paused);
+            match (has_next, active) {
+                (true, true) => self.play_current().await?,
+                (false, true) => self.playback_manager.lock().await.stop().await?,
+                _ => {}
+            }
+        }
+        Ok(removed)
+    }
+    /// 记录当前歌单播放到的位置，切换回来时从这里继续
+    async fn remember_position(&self) -> PlayerResult<()> {
+        let bvid = self
+            .playlist_manager
+            .get_cursor_music()
+            .await
+            .map(|music| music.bvid);
+        match self.active_playlist.lock().await.as_ref() {
+            Some(playlist) => {
+                self.playlists
+                    .set_last_bvid(playlist.id, bvid.as_deref())
+                    .await?
+            }
+            None => *self.library_last_bvid.lock().await = bvid,
+        }
+        Ok(())
+    }
+    /// 切换当前使用的歌单，name 为 None 时切换回音乐库
+    ///
+    /// 返回切换后的歌曲数量、当前歌曲索引和当前歌曲；切换前正在播放时继续播放新歌单的当前歌曲。
+    async fn switch_playlist(
+        &self,
+        name: Option<String>,
+    ) -> PlayerResult<(usize, Option<usize>, Option<MusicInfo>)> {
+        let target = match name {
+            Some(name) => Some(self.playlists.get_by_name(&name).await?),
+            None => None,
+        };
+        self.remember_position().await?;
+        let (records, last_bvid) = match &target {
+            Some(playlist) => (
+                self.playlists.list_musics(playlist.id).await?,
+                playlist.last_bvid.clone(),
+            ),
+            None => (
+                self.library.list_all().await?,
+                self.library_last_bvid.lock().await.clone(),
+            ),
+        };
+        let musics: Vec<MusicInfo> = records.into_iter().map(Into::into).collect();
+        let current = last_bvid.and_then(|bvid| musics.iter().position(|m| m.bvid == bvid));
+        self.playlist_manager
+            .replace_playlist(musics, current)
+            .await;
+        tracing::info!(
+            "Switched to playlist: {}",
+            target.as_ref().map_or("library", |p| p.name.as_str())
+        );
+        *self.active_playlist.lock().await = target;
+
+        let state = self
+            .playback_manager
+            .lock()
+            .await
+            .get_playback_state()
+            .await;
+        if state == PlaybackState::Playing {
+            self.play_current().await?;
+        }
+        Ok((
+            self.playlist_manager.get_playlist_len().await,
+            self.playlist_manager.get_current_index().await,
+            self.playlist_manager.get_current_music().await,
+        ))
+    }
+    /// 重命名歌单
+    async fn rename_playlist(&self, name: &str, new_name: &str) -> PlayerResult<()> {
+        self.playlists.rename(name, new_name).await?;
+        if let Some(playlist) = self.active_playlist.lock().await.as_mut()
+            && playlist.name == name
+        {
+            playlist.name = new_name.to_string();
+        }
+        Ok(())
+    }
+    /// 删除歌单，正在使用的歌单被删除时切换回音乐库
+    async fn delete_playlist(&self, name: &str) -> PlayerResult<()> {
+        let playlist = self.playlists.get_by_name(name).await?;
+        let is_active = self
+            .active_playlist
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|active| active.id == playlist.id);
+        if is_active {
+            self.switch_playlist(None).await?;
+        }
+        self.playlists.delete(name).await
+    }
+    /// 把歌曲加入命名歌单，音乐库中没有的歌曲会先写入音乐库
+    async fn add_to_named_playlist(&self, name: &str, music: MusicInfo) -> PlayerResult<MusicInfo> {
+        let playlist = self.playlists.get_by_name(name).await?;
+        let (music_id, music) = match self.library.find_by_bvid(&music.bvid).await? {
+            Some(record) => (record.id, MusicInfo::from(record)),
+            None => (self.library.upsert(&music).await?, music),
+        };
+        self.playlists.add_music(playlist.id, music_id).await?;
+        // 正在使用该歌单时同步到播放列表
+        let is_active = self
+            .active_playlist
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|active| active.id == playlist.id);
+        if is_active {
+            self.playlist_manager.add_music(music.clone()).await;
+        }
+        Ok(music)
+    }
+    /// 从命名歌单中移除歌曲，歌曲仍保留在音乐库中
+    async fn remove_from_named_playlist(&self, name: &str, bvid: &str) -> PlayerResult<()> {
+        let playlist = self.playlists.get_by_name(name).await?;
+        let bvid = bvid.trim();
+        let is_active = self
+            .active_playlist
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|active| active.id == playlist.id);
+        if is_active {
+            // 正在使用该歌单时按删除当前播放列表中的歌曲处理，会切换正在播放的歌曲
+            self.delete_from_playlist(DeletedRequest {
+                target: Some(Target::Bvid(bvid.to_string())),
+            })
+            .await?;
+        } else if !self.playlists.remove_music(playlist.id, bvid).await? {
+            return Err(PlayerError::NotFound(format!(
+                "{} is not in playlist {}",
+                bvid, name
+            )));
+        }
+        Ok(())
+    }
+    /// 获取所有歌单，音乐库排在最前面
+    async fn list_playlists(&self) -> PlayerResult<Vec<PlaylistInfo>> {
+        let active_id = self.active_playlist.lock().await.as_ref().map(|p| p.id);
+        let mut playlists = vec![PlaylistInfo {
+            name: String::new(),
+            track_count: self.library.count().await? as u32,
+            active: active_id.is_none(),
+            is_library: true,
+        }];
+        for playlist in self.playlists.list().await? {
+            playlists.push(PlaylistInfo {
+                active: active_id == Some(playlist.id),
+                name: playlist.name,
+                track_count: playlist.track_count as u32,
+                is_library: false,
+            });
+        }
+        Ok(playlists)
+    }
+    /// 调整播放列表顺序，正在使用命名歌单时同时保存新的顺序，返回调整后当前歌曲的索引
+    async fn reorder_playlist(&self, op: Reorder) -> PlayerResult<Option<usize>> {
+        self.playlist_manager.reorder(op).await?;
+        // 音乐库按加入顺序加载，调整后的顺序只在本次运行中有效
+        if let Some(playlist) = self.active_playlist.lock().await.as_ref() {
+            let len = self.playlist_manager.get_playlist_len().await;
+            let bvids: Vec<String> = self
+                .playlist_manager
+                .get_playlist_info(0, len)
+                .await
+                .into_iter()
+                .map(|music| music.bvid)
+                .collect();
+            self.playlists.set_order(playlist.id, &bvids).await?;
+        }
+        // 歌曲数量没有变化，状态对比发现不了，这里主动通知订阅者
+        self.emit(PlayerEvent::PlaylistChanged {
+            length: self.playlist_manager.get_playlist_len().await,
+        });
+        Ok(self.playlist_manager.get_current_index().await)
+    }
+    /// 把从 1 开始、包含两端的索引区间转换为从 0 开始的区间
+    fn index_range(start: u32, end: u32, len: usize) -> PlayerResult<Range<usize>> {
+        let (start, end) = (start as usize, end as usize);
+        if start == 0 || start > end || end > len {
+            return Err(PlayerError::InvalidIndex(format!(
+                "{}-{}/{}",
+                start, end, len
+            )));
+        }
+        Ok(start - 1..end)
+    }
     // 把命令处理逻辑抽到 handle_command
     async fn handle_command(&self, command: PlayerCommand) -> PlayerResult<()> {
         match command {
             PlayerCommand::Play => {
-                if let Some(music) = self.playlist_manager.get_current_music().await {
-                    let client = self.client.clone();
-                    let mut playback = self.playback_manager.lock().await;
-                    let volume = self.volume_manager.get_gstreamer_volume();
-                    playback.play_music(&client, &music, volume).await?;
-                }
+                self.play_current().await?;
+            }
+            PlayerCommand::PlayIndex { index, sender } => {
+                let result = self.play_index(index).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
             }
             PlayerCommand::PlayBvid(req) => {
                 if (self
                     .playlist_manager
-                    .add_will_play_music_into_playlist(&req.bvid)
+                    .add_will_play_music_into_playlist(&self.client, &req.bvid)
                     .await)
                     .is_ok()
-                    && let Some(music) = self.playlist_manager.get_current_music().await
                 {
-                    let client = self.client.clone();
-                    let mut playback = self.playback_manager.lock().await;
-                    let volume = self.volume_manager.get_gstreamer_volume();
-                    playback.play_music(&client, &music, volume).await?;
+                    self.play_current().await?;
                 }
             }
             PlayerCommand::Pause => {
@@ -160,42 +856,34 @@ impl AudioPlayer {
                 playback.resume().await?;
             }
             PlayerCommand::Next => {
-                if self.playlist_manager.move_to_next().await?
-                    && let Some(music) = self.playlist_manager.get_current_music().await
-                {
-                    let client = self.client.clone();
-                    let mut playback = self.playback_manager.lock().await;
-                    let volume = self.volume_manager.get_gstreamer_volume();
-                    playback.play_music(&client, &music, volume).await?;
-                }
+                self.play_next().await?;
             }
             PlayerCommand::Previous => {
-                if self.playlist_manager.move_to_previous().await?
-                    && let Some(music) = self.playlist_manager.get_current_music().await
-                {
-                    let client = self.client.clone();
-                    let mut playback = self.playback_manager.lock().await;
-                    let volume = self.volume_manager.get_gstreamer_volume();
-                    playback.play_music(&client, &music, volume).await?;
+                if self.playlist_manager.move_to_previous().await? {
+                    self.play_current().await?;
                 }
             }
             PlayerCommand::SetModel(req) => {
-                // 假设 SetModel 是切换播放模式（单曲、列表、随机等）
-                let mode = PlayMode::from_string(req.model.as_str()).unwrap();
+                // 切换播放模式（顺序、单曲循环、列表循环、随机）
+                let mode = PlayMode::from_string(req.model.as_str())
+                    .ok_or_else(|| PlayerError::Playlist(format!("Unknown mode: {}", req.model)))?;
                 self.playlist_manager.set_play_mode(mode).await;
+                if let Some(shuffle_loop) = req.shuffle_loop {
+                    self.playlist_manager.set_shuffle_loop(shuffle_loop).await;
+                }
             }
             PlayerCommand::SetVolume(req) => {
                 let playback = self.playback_manager.lock().await;
                 let pipeline = playback.get_pipeline().clone();
                 self.volume_manager.set_volume(&pipeline, req.volume)?;
             }
-            PlayerCommand::AddPlaylist(_req) => {
-                // for music in req.musics {
-                //     self.playlist_manager.add_music(music).await;
-                // }
+            PlayerCommand::AddPlaylist { music, sender } => {
+                let result = self.add_to_playlist(music).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
             }
-            PlayerCommand::Delete(_req) => {
-                // self.playlist_manager.remove_by_id(&req.id).await;
+            PlayerCommand::Delete { request, sender } => {
+                let result = self.delete_from_playlist(request).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
             }
             PlayerCommand::GetState(sender) => {
                 let state = self.get_current_state().await;
@@ -227,9 +915,73 @@ impl AudioPlayer {
                 };
                 let _ = sender.send(music_page_info); // 忽略发送失败（调用方可能已 drop）
             }
-            PlayerCommand::Seek(_position_micros) => {
-                // let duration = std::time::Duration::from_micros(position_micros);
-                // self.playback_manager.seek(duration).await?;
+            PlayerCommand::Find { query, sender } => {
+                let results = self.playlist_manager.find_music(&query).await;
+                let _ = sender.send(results); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::Queue {
+                music,
+                position,
+                sender,
+            } => {
+                let result = self.queue_music(music, position).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::ClearQueue(sender) => {
+                let cleared = self.playlist_manager.clear_queue().await;
+                let _ = sender.send(cleared); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::ListQueue(sender) => {
+                let musics = self.playlist_manager.get_queue().await;
+                let _ = sender.send(musics); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::CreatePlaylist { name, sender } => {
+                let result = self.playlists.create(&name).await.map(|_| ());
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::RenamePlaylist {
+                name,
+                new_name,
+                sender,
+            } => {
+                let result = self.rename_playlist(&name, &new_name).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::DeletePlaylist { name, sender } => {
+                let result = self.delete_playlist(&name).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::AddToPlaylist {
+                name,
+                music,
+                sender,
+            } => {
+                let result = self.add_to_named_playlist(&name, music).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::RemoveFromPlaylist { name, bvid, sender } => {
+                let result = self.remove_from_named_playlist(&name, &bvid).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::SwitchPlaylist { name, sender } => {
+                let result = self.switch_playlist(name).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::ListPlaylists(sender) => {
+                let result = self.list_playlists().await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::Reorder { op, sender } => {
+                let result = self.reorder_playlist(op).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::Subscribe(sender) => {
+                let _ = sender.send(self.subscribe()); // 忽略发送失败（调用方可能已 drop）
+            }
+            PlayerCommand::Seek { target, sender } => {
+                let playback = self.playback_manager.lock().await;
+                let result = playback.seek_to(target).await;
+                let _ = sender.send(result); // 忽略发送失败（调用方可能已 drop）
             }
         }
         Ok(())
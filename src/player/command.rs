@@ -1,14 +1,25 @@
 use crate::{
+    errors::PlayerResult,
     pb::{
-        AddPlaylistRequest, DeletedRequest, PlayBvidRequest, SetModelRequest, SetVolumeRequest,
+        DeletedRequest, PlayBvidRequest, PlaylistInfo, SetModelRequest, SetVolumeRequest,
         ShowMusicPageInfoResponse,
     },
-    player::state::PlayerState,
+    player::{
+        event::PlayerEvent, model::MusicInfo, playback::SeekTarget, queue::QueuePosition,
+        reorder::Reorder, search::MusicQuery, state::PlayerState,
+    },
 };
 
+/// 需要返回处理结果的命令所携带的应答通道
+pub type Responder<T> = tokio::sync::oneshot::Sender<PlayerResult<T>>;
+
 #[derive(Debug)]
 pub enum PlayerCommand {
     Play,
+    PlayIndex {
+        index: usize,
+        sender: Responder<MusicInfo>,
+    },
     PlayBvid(PlayBvidRequest),
     Pause,
     Next,
@@ -17,12 +28,84 @@ pub enum PlayerCommand {
     Resume,
     SetModel(SetModelRequest),
     SetVolume(SetVolumeRequest),
-    AddPlaylist(AddPlaylistRequest),
-    Delete(DeletedRequest),
+    /// 歌曲信息在发送命令前获取，请求 B 站期间不阻塞其他命令
+    AddPlaylist {
+        music: MusicInfo,
+        sender: Responder<(usize, MusicInfo)>,
+    },
+    Delete {
+        request: DeletedRequest,
+        sender: Responder<Vec<MusicInfo>>,
+    },
     GetState(tokio::sync::oneshot::Sender<PlayerState>),
     ShowMusicPageInfo {
         page: u32,
         sender: tokio::sync::oneshot::Sender<ShowMusicPageInfoResponse>,
     },
-    Seek(u64),
+    Find {
+        query: MusicQuery,
+        sender: tokio::sync::oneshot::Sender<Vec<(usize, MusicInfo)>>,
+    },
+    /// 歌曲信息在发送命令前获取，播放列表中已有该歌曲时使用播放列表中的信息
+    Queue {
+        music: MusicInfo,
+        position: QueuePosition,
+        sender: Responder<(usize, MusicInfo)>,
+    },
+    ClearQueue(tokio::sync::oneshot::Sender<usize>),
+    ListQueue(tokio::sync::oneshot::Sender<Vec<MusicInfo>>),
+    CreatePlaylist {
+        name: String,
+        sender: Responder<()>,
+    },
+    RenamePlaylist {
+        name: String,
+        new_name: String,
+        sender: Responder<()>,
+    },
+    DeletePlaylist {
+        name: String,
+        sender: Responder<()>,
+    },
+    /// 音乐库中已有该歌曲时使用音乐库中的信息
+    AddToPlaylist {
+        name: String,
+        music: MusicInfo,
+        sender: Responder<MusicInfo>,
+    },
+    RemoveFromPlaylist {
+        name: String,
+        bvid: String,
+        sender: Responder<()>,
+    },
+    /// name 为 None 时切换回音乐库，返回 (歌曲数量, 当前索引, 当前歌曲)
+    SwitchPlaylist {
+        name: Option<String>,
+        sender: Responder<(usize, Option<usize>, Option<MusicInfo>)>,
+    },
+    ListPlaylists(Responder<Vec<PlaylistInfo>>),
+    /// 调整播放列表顺序，返回调整后当前歌曲的索引
+    Reorder {
+        op: Reorder,
+        sender: Responder<Option<usize>>,
+    },
+    Subscribe(tokio::sync::oneshot::Sender<tokio::sync::broadcast::Receiver<PlayerEvent>>),
+    Seek {
+        target: SeekTarget,
+        sender: Responder<(gstreamer::ClockTime, gstreamer::ClockTime)>,
+    },
+}
+impl PlayerCommand {
+    /// 只查询状态的命令，不会改变需要保存的会话
+    pub fn is_query(&self) -> bool {
+        matches!(
+            self,
+            PlayerCommand::GetState(_)
+                | PlayerCommand::ShowMusicPageInfo { .. }
+                | PlayerCommand::Find { .. }
+                | PlayerCommand::ListQueue(_)
+                | PlayerCommand::ListPlaylists(_)
+                | PlayerCommand::Subscribe(_)
+        )
+    }
 }
@@ -1,3 +1,104 @@
+use gstreamer::format::FormattedValue;
+
+use crate::{
+    pb::player_event::Event,
+    player::{model::MusicInfo, play_mode::PlayMode, playback::PlaybackState, state::PlayerState},
+};
+
+/// 推送给订阅者的播放器事件
+#[derive(Debug, Clone)]
+pub enum PlayerEvent {
+    /// 切换了当前歌曲
+    TrackChanged {
+        index: Option<usize>,
+        music: MusicInfo,
+    },
+    /// 播放状态变化
+    StateChanged(PlaybackState),
+    /// 播放进度
+    Position {
+        position: Option<gstreamer::ClockTime>,
+        duration: Option<gstreamer::ClockTime>,
+    },
+    /// 音量变化
+    VolumeChanged(u32),
+    /// 播放模式变化
+    ModeChanged(PlayMode),
+    /// 播放列表变化
+    PlaylistChanged { length: usize },
+    /// 执行命令时出错
+    Error(String),
+}
+
+impl PlayerEvent {
+    /// 比较前后两次播放器状态，生成对应的变化事件（不包含播放进度）
+    pub fn diff(previous: Option<&PlayerState>, current: &PlayerState) -> Vec<PlayerEvent> {
+        let mut events = Vec::new();
+        let bvid = |state: &PlayerState| state.current_music.as_ref().map(|m| m.bvid.clone());
+        if previous.is_none_or(|prev| {
+            bvid(prev) != bvid(current) || prev.current_index != current.current_index
+        }) && let Some(music) = &current.current_music
+        {
+            events.push(PlayerEvent::TrackChanged {
+                index: current.current_index,
+                music: music.clone(),
+            });
+        }
+        if previous.is_none_or(|prev| prev.playback_state != current.playback_state) {
+            events.push(PlayerEvent::StateChanged(current.playback_state));
+        }
+        if previous.is_none_or(|prev| prev.volume != current.volume) {
+            events.push(PlayerEvent::VolumeChanged(current.volume));
+        }
+        if previous.is_none_or(|prev| prev.play_mode != current.play_mode) {
+            events.push(PlayerEvent::ModeChanged(current.play_mode));
+        }
+        if previous.is_none_or(|prev| prev.playlist_length != current.playlist_length) {
+            events.push(PlayerEvent::PlaylistChanged {
+                length: current.playlist_length,
+            });
+        }
+        events
+    }
+}
+
+/// 转换为 gRPC 中的播放器事件
+impl From<PlayerEvent> for crate::pb::PlayerEvent {
+    fn from(event: PlayerEvent) -> Self {
+        // 时间未知时对应字段不设置
+        let known_ms =
+            |time: Option<gstreamer::ClockTime>| time.filter(|t| t.is_some()).map(|t| t.mseconds());
+        let event = match event {
+            PlayerEvent::TrackChanged { index, music } => {
+                Event::TrackChanged(crate::pb::TrackChanged {
+                    index: index.map(|index| index as u32 + 1),
+                    music: Some(music.into()),
+                })
+            }
+            PlayerEvent::StateChanged(state) => {
+                Event::StateChanged(crate::pb::PlaybackState::from(state) as i32)
+            }
+            PlayerEvent::Position { position, duration } => {
+                Event::Position(crate::pb::PositionTick {
+                    position_ms: known_ms(position),
+                    duration_ms: known_ms(duration),
+                })
+            }
+            PlayerEvent::VolumeChanged(volume) => Event::VolumeChanged(volume),
+            PlayerEvent::ModeChanged(mode) => {
+                Event::ModeChanged(crate::pb::PlayMode::from(mode) as i32)
+            }
+            PlayerEvent::PlaylistChanged { length } => {
+                Event::PlaylistChanged(crate::pb::PlaylistChanged {
+                    length: length as u32,
+                })
+            }
+            PlayerEvent::Error(error) => Event::Error(error),
+        };
+        Self { event: Some(event) }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum EosEvent {
     /// 正常播放结束
@@ -7,5 +7,8 @@ pub mod music_data;
 pub mod play_mode;
 pub mod playback;
 pub mod playlist;
+pub mod queue;
+pub mod reorder;
+pub mod search;
 pub mod state;
 pub mod volume;
@@ -7,6 +7,45 @@ pub struct MusicInfo {
     pub owner: String,          // 夕照影音
     pub duration: u64,          // 227 秒 03:47
 }
+/// 转换为 gRPC 中的音乐信息
+impl From<MusicInfo> for crate::pb::MusicInfo {
+    fn from(music: MusicInfo) -> Self {
+        Self {
+            bvid: music.bvid,
+            cid: music.cid,
+            title: music.title,
+            artist: music.artist.unwrap_or_default(),
+            owner: music.owner,
+            duration: music.duration,
+        }
+    }
+}
+/// 从 gRPC 中的音乐信息转换
+impl From<crate::pb::MusicInfo> for MusicInfo {
+    fn from(music: crate::pb::MusicInfo) -> Self {
+        Self {
+            bvid: music.bvid,
+            cid: music.cid,
+            title: music.title,
+            artist: Some(music.artist).filter(|artist| !artist.is_empty()),
+            owner: music.owner,
+            duration: music.duration,
+        }
+    }
+}
+/// 从视频信息转换，视频信息中没有歌手
+impl From<crate::fetch::network::VideoData> for MusicInfo {
+    fn from(video: crate::fetch::network::VideoData) -> Self {
+        Self {
+            bvid: video.bvid,
+            cid: video.cid.to_string(),
+            title: video.title,
+            artist: None,
+            owner: video.owner.name,
+            duration: video.duration,
+        }
+    }
+}
 /// 实现数据展示
 impl std::fmt::Display for MusicInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
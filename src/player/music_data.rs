@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{BufRead, BufReader},
+    path::Path,
 };
 
 use crate::player::model::MusicInfo;
@@ -39,48 +40,173 @@ pub fn get_music_data() -> Vec<MusicInfo> {
     };
     vec![music_info1, music_info2, music_info3, music_info4]
 }
+/// musics.txt 中单行解析失败的原因
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MusicLineError {
+    #[error("未找到 bvid（BV 加 10 位字符）")]
+    MissingBvid,
+    #[error("bvid 后缺少数字 cid")]
+    MissingCid,
+    #[error("缺少标题或时长")]
+    MissingTitle,
+    #[error("无法解析时长: {0}")]
+    InvalidDuration(String),
+    #[error("缺少歌手或上传者")]
+    MissingArtist,
+    #[error("无法确定歌手和上传者的分界: {0}")]
+    AmbiguousArtist(String),
+    #[error("不是 UTF-8 编码")]
+    InvalidUtf8,
+}
+
+/// 带行号的解析错误
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("第 {line_number} 行: {reason} ({content})")]
+pub struct MusicParseError {
+    pub line_number: usize, // 行号，从 1 开始
+    pub content: String,    // 原始内容
+    pub reason: MusicLineError,
+}
+
 // 《天高地厚》-05:01-BV1b14y1Z7Uh-1116042344-信乐团-知己音乐
-/// 从文件中读取音乐信息
+/// 解析一行音乐信息
+///
+/// 以 `-BV..........-cid-` 为锚点把一行切成三段，因此标题、歌手和上传者中都可以出现 `-`：
+/// - 锚点之前最后一个 `-` 分隔标题和时长，时长支持 `mm:ss` 和 `h:mm:ss`
+/// - 锚点之后是 `歌手-上传者`，有多个 `-` 时选择上传者以歌手名开头的切分
+///   （如 `A-Lin-A-Lin官方`），找不到这样的切分时返回 `AmbiguousArtist`
+pub fn parse_music_line(line: &str) -> Result<MusicInfo, MusicLineError> {
+    let (head, bvid, cid, tail) = split_at_bvid(line.trim())?;
+    // 标题和时长
+    let (title, duration) = head.rsplit_once('-').ok_or(MusicLineError::MissingTitle)?;
+    let title = title.trim();
+    let title = title.strip_prefix('《').unwrap_or(title);
+    let title = title.strip_suffix('》').unwrap_or(title).trim();
+    if title.is_empty() {
+        return Err(MusicLineError::MissingTitle);
+    }
+    let duration = get_duration_from_str(duration.trim())
+        .ok_or_else(|| MusicLineError::InvalidDuration(duration.trim().to_string()))?;
+    // 歌手和上传者
+    let (artist, owner) = split_artist_owner(tail)?;
+    Ok(MusicInfo {
+        bvid: bvid.to_string(),
+        cid: cid.to_string(),
+        title: title.to_string(),
+        artist: Some(artist.trim().to_string()).filter(|artist| !artist.is_empty()),
+        duration,
+        owner: owner.trim().to_string(),
+    })
+}
+/// 逐行解析，空行会被忽略
+///
+/// 不是 UTF-8 编码的行作为该行的解析错误返回，读取失败时返回 I/O 错误。
+pub fn parse_music_lines(
+    mut reader: impl BufRead,
+) -> std::io::Result<Vec<Result<MusicInfo, MusicParseError>>> {
+    let mut results = Vec::new();
+    let mut buffer = Vec::new();
+    let mut line_number = 0;
+    loop {
+        buffer.clear();
+        if reader.read_until(b'\n', &mut buffer)? == 0 {
+            break;
+        }
+        line_number += 1;
+        let line = String::from_utf8_lossy(&buffer)
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        if line.trim().is_empty() {
+            continue;
+        }
+        let result = match std::str::from_utf8(&buffer) {
+            Ok(_) => parse_music_line(&line),
+            Err(_) => Err(MusicLineError::InvalidUtf8),
+        };
+        results.push(result.map_err(|reason| MusicParseError {
+            line_number,
+            content: line,
+            reason,
+        }));
+    }
+    Ok(results)
+}
+/// 解析 musics.txt 格式的文件，返回每一行的解析结果
+pub fn parse_music_file(
+    path: impl AsRef<Path>,
+) -> std::io::Result<Vec<Result<MusicInfo, MusicParseError>>> {
+    let file = File::open(path)?;
+    parse_music_lines(BufReader::new(file))
+}
+/// 从文件中读取音乐信息，无法解析的行会被记录到日志并跳过
 pub fn read_music_data(file: &str) -> Vec<MusicInfo> {
-    let mut music_data = Vec::new();
-    if let Ok(file) = File::open(file) {
-        let reader = BufReader::new(file);
-        for line in reader.lines().map_while(Result::ok) {
-            let parts: Vec<&str> = line.split('-').collect();
-            if parts.len() == 6 {
-                let title = parts[0]
-                    .replace("《", "")
-                    .replace("》", "")
-                    .trim()
-                    .to_string();
-                let duration = get_duration_from_str(parts[1].trim()) as u64;
-                let bvid = parts[2].trim().to_string();
-                let cid = parts[3].trim().to_string();
-                let artist = parts[4].trim().to_string();
-                let owner = parts[5].trim().to_string();
-                let music_info = MusicInfo {
-                    bvid,
-                    cid,
-                    title,
-                    artist: Some(artist),
-                    duration,
-                    owner,
-                };
-                music_data.push(music_info);
-            }
+    let Ok(results) = parse_music_file(file) else {
+        return Vec::new();
+    };
+    results
+        .into_iter()
+        .filter_map(|result| result.inspect_err(|e| tracing::warn!("Skipped {}", e)).ok())
+        .collect()
+}
+
+/// 找到 `-BV..........-cid-` 锚点，返回 (锚点前, bvid, cid, 锚点后)
+fn split_at_bvid(line: &str) -> Result<(&str, &str, &str, &str), MusicLineError> {
+    let mut error = MusicLineError::MissingBvid;
+    for (dash, _) in line.match_indices("-BV") {
+        let bvid_start = dash + 1;
+        let Some(bvid) = line.get(bvid_start..bvid_start + 12) else {
+            continue;
+        };
+        if !bvid[2..].bytes().all(|b| b.is_ascii_alphanumeric()) {
+            continue;
+        }
+        // bvid 后面必须紧跟 `-数字-`
+        let rest = &line[bvid_start + 12..];
+        let cid_len = rest
+            .strip_prefix('-')
+            .map(|rest| rest.bytes().take_while(u8::is_ascii_digit).count())
+            .unwrap_or(0);
+        if cid_len == 0 || !rest[1 + cid_len..].starts_with('-') {
+            error = MusicLineError::MissingCid;
+            continue;
         }
+        let cid = &rest[1..1 + cid_len];
+        let tail = &rest[2 + cid_len..];
+        return Ok((&line[..dash], bvid, cid, tail));
     }
-    music_data
+    Err(error)
 }
 
-fn get_duration_from_str(duration_str: &str) -> u32 {
-    let parts: Vec<&str> = duration_str.split(':').collect();
-    if parts.len() == 2 {
-        let minutes = parts[0].parse::<u32>().unwrap_or(0);
-        let seconds = parts[1].parse::<u32>().unwrap_or(0);
-        minutes * 60 + seconds
-    } else {
-        0
+/// 切分 `歌手-上传者`，有多个 `-` 且上传者都不以歌手名开头时无法确定分界
+fn split_artist_owner(tail: &str) -> Result<(&str, &str), MusicLineError> {
+    let dashes: Vec<usize> = tail.match_indices('-').map(|(i, _)| i).collect();
+    let split = match dashes.as_slice() {
+        [] => return Err(MusicLineError::MissingArtist),
+        [only] => *only,
+        _ => dashes
+            .iter()
+            .copied()
+            .find(|&i| {
+                let artist = tail[..i].trim();
+                !artist.is_empty() && tail[i + 1..].trim_start().starts_with(artist)
+            })
+            .ok_or_else(|| MusicLineError::AmbiguousArtist(tail.trim().to_string()))?,
+    };
+    Ok((&tail[..split], &tail[split + 1..]))
+}
+
+/// 把 `mm:ss` 或 `h:mm:ss` 格式的时长转换为秒
+fn get_duration_from_str(duration_str: &str) -> Option<u64> {
+    let parts = duration_str
+        .split(':')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    match parts.as_slice() {
+        [minutes, seconds] if *seconds < 60 => Some(minutes * 60 + seconds),
+        [hours, minutes, seconds] if *minutes < 60 && *seconds < 60 => {
+            Some(hours * 3600 + minutes * 60 + seconds)
+        }
+        _ => None,
     }
 }
 
@@ -14,12 +14,45 @@ impl PlayMode {
             PlayMode::RepeatAll => "全曲循环".to_string(),
         }
     }
+    /// 保存到数据库时使用的名称，可以被 `from_string` 解析
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayMode::Normal => "normal",
+            PlayMode::Shuffle => "shuffle",
+            PlayMode::Repeat => "repeat",
+            PlayMode::RepeatAll => "repeat_all",
+        }
+    }
+    /// 从字符串解析播放模式，无法识别时返回 None
     pub fn from_string(s: &str) -> Option<PlayMode> {
-        match s {
+        match s.trim().to_lowercase().as_str() {
+            "normal" => Some(PlayMode::Normal),
             "shuffle" => Some(PlayMode::Shuffle),
             "repeat" => Some(PlayMode::Repeat),
-            "repeat_all" => Some(PlayMode::RepeatAll),
-            _ => Some(PlayMode::Normal),
+            "repeat_all" | "repeatall" | "repeat-all" => Some(PlayMode::RepeatAll),
+            _ => None,
+        }
+    }
+}
+/// 转换为 gRPC 中的播放模式
+impl From<PlayMode> for crate::pb::PlayMode {
+    fn from(mode: PlayMode) -> Self {
+        match mode {
+            PlayMode::Normal => Self::Normal,
+            PlayMode::Shuffle => Self::Shuffle,
+            PlayMode::Repeat => Self::Repeat,
+            PlayMode::RepeatAll => Self::RepeatAll,
+        }
+    }
+}
+/// 从 gRPC 中的播放模式转换
+impl From<crate::pb::PlayMode> for PlayMode {
+    fn from(mode: crate::pb::PlayMode) -> Self {
+        match mode {
+            crate::pb::PlayMode::Normal => Self::Normal,
+            crate::pb::PlayMode::Shuffle => Self::Shuffle,
+            crate::pb::PlayMode::Repeat => Self::Repeat,
+            crate::pb::PlayMode::RepeatAll => Self::RepeatAll,
         }
     }
 }
@@ -14,8 +14,13 @@ use tokio::{
 
 use crate::{
     errors::{PlayerError, PlayerResult},
-    fetch::verify::fetch_and_verify_audio_url,
-    player::{audio_chain::AudioChainBuilder, model::MusicInfo},
+    fetch::{
+        cdn::CdnHosts,
+        credentials::CredentialStore,
+        stream::{AudioQuality, StreamSelector},
+        verify::fetch_and_verify_audio_stream,
+    },
+    player::{audio_chain::AudioChainBuilder, event::EosEvent, model::MusicInfo},
 };
 /// 定义播放状态的枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,13 +57,94 @@ impl PlaybackState {
         }
     }
 }
+/// 转换为 gRPC 中的播放状态
+impl From<PlaybackState> for crate::pb::PlaybackState {
+    fn from(state: PlaybackState) -> Self {
+        match state {
+            PlaybackState::Idle => Self::Idle,
+            PlaybackState::Ready => Self::Ready,
+            PlaybackState::Playing => Self::Playing,
+            PlaybackState::Paused => Self::Paused,
+            PlaybackState::Ended => Self::Ended,
+            PlaybackState::Error => Self::Error,
+            PlaybackState::Stopped => Self::Stopped,
+        }
+    }
+}
+/// 从 gRPC 中的播放状态转换
+impl From<crate::pb::PlaybackState> for PlaybackState {
+    fn from(state: crate::pb::PlaybackState) -> Self {
+        match state {
+            crate::pb::PlaybackState::Idle => Self::Idle,
+            crate::pb::PlaybackState::Ready => Self::Ready,
+            crate::pb::PlaybackState::Playing => Self::Playing,
+            crate::pb::PlaybackState::Paused => Self::Paused,
+            crate::pb::PlaybackState::Ended => Self::Ended,
+            crate::pb::PlaybackState::Error => Self::Error,
+            crate::pb::PlaybackState::Stopped => Self::Stopped,
+        }
+    }
+}
+/// 定义跳转目标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeekTarget {
+    Absolute(gstreamer::ClockTime), // 跳转到绝对位置
+    Relative(i64),                  // 相对当前位置偏移的毫秒数，可为负数
+    Percent(f64),                   // 按总时长百分比跳转，取值 0-100
+}
+impl SeekTarget {
+    /// 根据当前位置和总时长计算出实际要跳转到的位置
+    pub fn resolve(
+        &self,
+        position: Option<gstreamer::ClockTime>,
+        duration: Option<gstreamer::ClockTime>,
+    ) -> PlayerResult<gstreamer::ClockTime> {
+        let target = match *self {
+            Self::Absolute(target) => target,
+            Self::Relative(offset_ms) => {
+                let position = position.ok_or_else(|| {
+                    PlayerError::StateTransition("Current position is unknown".into())
+                })?;
+                let target_ms = position.mseconds() as i64 + offset_ms;
+                // 往回跳过头时停在开头
+                gstreamer::ClockTime::from_mseconds(target_ms.max(0) as u64)
+            }
+            Self::Percent(percent) => {
+                if !(0.0..=100.0).contains(&percent) {
+                    return Err(PlayerError::SeekOutOfRange(format!(
+                        "{}% is not between 0% and 100%",
+                        percent
+                    )));
+                }
+                let duration = duration
+                    .ok_or_else(|| PlayerError::StateTransition("Duration is unknown".into()))?;
+                let target_ms = duration.mseconds() as f64 * percent / 100.0;
+                gstreamer::ClockTime::from_mseconds(target_ms as u64)
+            }
+        };
+        // 超出总时长的跳转直接拒绝
+        if let Some(duration) = duration
+            && target > duration
+        {
+            return Err(PlayerError::SeekOutOfRange(format!(
+                "{}ms exceeds duration {}ms",
+                target.mseconds(),
+                duration.mseconds()
+            )));
+        }
+        Ok(target)
+    }
+}
 pub struct PlaybackManager {
-    pub pipeline: gstreamer::Pipeline,             // 播放通道
-    pub playback_state: Arc<Mutex<PlaybackState>>, // 播放状态
-    pub current_music: Mutex<Option<MusicInfo>>,   // 当前播放音乐信息
-    pub eos_sender: Option<mpsc::Sender<()>>,      // 播放结束信号发送器
-    stop_flag: Arc<AtomicBool>,                    // 是否需要停止
-    current_bus_watcher: Option<JoinHandle<()>>,   // 当前正在运行的后台监听任务句柄
+    pub pipeline: gstreamer::Pipeline,                // 播放通道
+    pub playback_state: Arc<Mutex<PlaybackState>>,    // 播放状态
+    pub current_music: Mutex<Option<MusicInfo>>,      // 当前播放音乐信息
+    pub current_quality: Mutex<Option<AudioQuality>>, // 当前音轨的音质
+    pub stream_selector: StreamSelector,              // 按音质偏好选择音轨
+    pub cdn_hosts: CdnHosts,                          // 候选 CDN 地址和上次可用的域名
+    pub eos_sender: Option<mpsc::Sender<EosEvent>>,   // 播放结束信号发送器
+    stop_flag: Arc<AtomicBool>,                       // 是否需要停止
+    current_bus_watcher: Option<JoinHandle<()>>,      // 当前正在运行的后台监听任务句柄
 }
 // === 新增字段（用于管理后台监听任务）===
 // stop_flag
@@ -69,11 +155,14 @@ pub struct PlaybackManager {
 // 这样在切换歌曲或停止时，我们可以知道是否有旧任务需要清理
 impl PlaybackManager {
     /// PlaybackManager 的构造函数
-    pub fn new(pipeline: gstreamer::Pipeline, eos_sender: Option<mpsc::Sender<()>>) -> Self {
+    pub fn new(pipeline: gstreamer::Pipeline, eos_sender: Option<mpsc::Sender<EosEvent>>) -> Self {
         Self {
             pipeline,
             playback_state: Arc::new(Mutex::new(PlaybackState::Idle)),
             current_music: Mutex::new(None),
+            current_quality: Mutex::new(None),
+            stream_selector: StreamSelector::default(),
+            cdn_hosts: CdnHosts::default(),
             eos_sender,
             stop_flag: Arc::new(AtomicBool::new(false)),
             current_bus_watcher: None,
@@ -83,17 +172,24 @@ impl PlaybackManager {
     pub fn get_pipeline(&self) -> &gstreamer::Pipeline {
         &self.pipeline
     }
-    /// 播放音乐
+    /// 播放音乐，音频地址属于 B 站域名时请求音频会携带登录 Cookie
     pub async fn play_music(
         &mut self,
         client: &reqwest::Client,
         music: &MusicInfo,
         volume: f64,
+        credentials: &CredentialStore,
     ) -> PlayerResult<()> {
         // 1️⃣ 获取音频真实播放 URL（调用 Bilibili API）
-        let url = fetch_and_verify_audio_url(client, &music.bvid, &music.cid)
-            .await
-            .map_err(|_| PlayerError::FetchError("Fetch audio URL failed".into()))?;
+        let (stream, url) = fetch_and_verify_audio_stream(
+            client,
+            &music.bvid,
+            &music.cid,
+            &self.stream_selector,
+            &self.cdn_hosts,
+        )
+        .await
+        .map_err(|_| PlayerError::FetchError("Fetch audio URL failed".into()))?;
 
         // 2️⃣ 停止当前正在播放的音乐（清理旧资源）
         //    这会触发 stop_flag 设置 + 旧任务清理 + pipeline 重置
@@ -101,12 +197,16 @@ impl PlaybackManager {
 
         // 3️⃣ 为新歌曲构建 GStreamer 播放管道
         //    （内部会设置 URI、音量、总线等）
-        self.build_pipeline(url.as_str(), volume).await?;
+        //    登录 Cookie 不发送给 CDN 节点，CDN 地址已经带有签名
+        let cookie = credentials.cookie_header(&url);
+        self.build_pipeline(url.as_str(), volume, cookie.as_deref())
+            .await?;
 
         // 4️⃣ 更新当前播放的音乐信息（供状态查询使用）
         {
             let mut current_music = self.current_music.lock().await;
             *current_music = Some(music.clone());
+            *self.current_quality.lock().await = stream.quality();
         }
         // 5️⃣ 更新全局播放状态为 "Playing"
         {
@@ -138,6 +238,8 @@ impl PlaybackManager {
         // 9️⃣ 克隆需要在后台任务中使用的数据
         let eos_sender = self.eos_sender.clone(); // 通道可能为空（可选）
         let music_title = music.title.clone(); // 用于日志
+        let (bvid, cid) = (music.bvid.clone(), music.cid.clone()); // 用于网络错误重试
+        let playback_state = self.playback_state.clone(); // 播放结束或出错时更新状态
         // let pipeline = self.pipeline.clone(); // 假设 pipeline 是 Arc<...>，否则需要调整
 
         // 🔟 启动后台线程监听 GStreamer 消息（关键！不阻塞 async 任务）
@@ -163,9 +265,10 @@ impl PlaybackManager {
                         // 🎯 播放正常结束（End Of Stream）
                         MessageView::Eos(_) => {
                             tracing::info!("Playback finished: {}", music_title);
+                            *playback_state.blocking_lock() = PlaybackState::Ended;
                             // 通知主逻辑：可以播放下一首了
                             if let Some(sender) = &eos_sender {
-                                let _ = sender.blocking_send(());
+                                let _ = sender.blocking_send(EosEvent::NormalEnd);
                             }
                             break; // 退出监听循环
                         }
@@ -178,6 +281,22 @@ impl PlaybackManager {
                                 err.error(),
                                 err.debug().unwrap_or_default()
                             );
+                            *playback_state.blocking_lock() = PlaybackState::Error;
+                            // 读取网络资源失败（如播放地址过期）时可以重新获取地址
+                            let error = err.error();
+                            let event = if error.kind::<gstreamer::ResourceError>().is_some() {
+                                EosEvent::NetworkError {
+                                    error: error.to_string(),
+                                    bvid: bvid.clone(),
+                                    cid: cid.clone(),
+                                }
+                            } else {
+                                EosEvent::from(error)
+                            };
+                            // 通知主逻辑：重试或跳过当前歌曲
+                            if let Some(sender) = &eos_sender {
+                                let _ = sender.blocking_send(event);
+                            }
                             break; // 退出监听循环
                         }
 
@@ -269,6 +388,7 @@ impl PlaybackManager {
         {
             let mut current_music = self.current_music.lock().await;
             *current_music = None;
+            *self.current_quality.lock().await = None;
         }
 
         // 6️⃣ 更新全局播放状态为 "Stopped"
@@ -279,6 +399,13 @@ impl PlaybackManager {
         }
         Ok(())
     }
+    /// 播放列表已经播放完毕，停止播放并把状态设为播放结束
+    pub async fn finish(&mut self) -> PlayerResult<()> {
+        self.stop().await?;
+        *self.playback_state.lock().await = PlaybackState::Ended;
+        tracing::info!("Playback state set to: Ended");
+        Ok(())
+    }
     /// 获取当前播放状态
     pub async fn get_playback_state(&self) -> PlaybackState {
         let state = self.playback_state.lock().await;
@@ -296,6 +423,10 @@ impl PlaybackManager {
             _ => None,
         }
     }
+    /// 获取当前音轨的音质
+    pub async fn get_audio_quality(&self) -> Option<AudioQuality> {
+        *self.current_quality.lock().await
+    }
     /// 获取音乐总时长
     pub async fn get_duration(&self) -> Option<gstreamer::ClockTime> {
         // 创建总时长查询对象
@@ -318,6 +449,7 @@ impl PlaybackManager {
                 || *state == PlaybackState::Error
                 || *state == PlaybackState::Ready
                 || *state == PlaybackState::Ended
+                || *state == PlaybackState::Stopped
             {
                 return Err(PlayerError::StateTransition(
                     "Cannot seek while not playing or paused".into(),
@@ -334,6 +466,17 @@ impl PlaybackManager {
         tracing::debug!("Sought to {:?}", position);
         Ok(())
     }
+    /// 按跳转目标跳转，返回跳转后的位置和总时长
+    pub async fn seek_to(
+        &self,
+        target: SeekTarget,
+    ) -> PlayerResult<(gstreamer::ClockTime, gstreamer::ClockTime)> {
+        let position = self.get_current_position().await;
+        let duration = self.get_duration().await;
+        let target = target.resolve(position, duration)?;
+        self.seek(target).await?;
+        Ok((target, duration.unwrap_or(gstreamer::ClockTime::ZERO)))
+    }
     // /// 添加 watch bus 用来接收播放状态变化
     // pub async fn watch_bus(&self) {
     //     let bus = self.pipeline.bus().expect("Pipeline should have a bus");
@@ -426,11 +569,17 @@ impl PlaybackManager {
     // }
 
     /// 构建播放器管道
-    async fn build_pipeline(&mut self, url: &str, volume: f64) -> PlayerResult<()> {
+    async fn build_pipeline(
+        &mut self,
+        url: &str,
+        volume: f64,
+        cookie: Option<&str>,
+    ) -> PlayerResult<()> {
         // 创建元素
         let (pipeline, _elements) = AudioChainBuilder::default()
             .with_url(url)
             .with_volume(volume)
+            .with_cookie(cookie)
             .build()
             .map_err(|e| {
                 PlayerError::AudioElement(format!("Failed to build audio chain: {}", e))
@@ -1,19 +1,37 @@
-use std::collections::VecDeque;
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
-use rand::seq::SliceRandom;
+use rand::{Rng, seq::SliceRandom};
 use tokio::sync::{Mutex, RwLock};
 
 use crate::{
     errors::{PlayerError, PlayerResult},
     fetch::network::fetch_video_data,
-    player::{model::MusicInfo, play_mode::PlayMode},
+    player::{
+        model::MusicInfo,
+        play_mode::PlayMode,
+        queue::{QueuePosition, UpNextQueue},
+        reorder::{Reorder, SortKey},
+        search::MusicQuery,
+    },
 };
 
+/// 播放历史最多保留的歌曲数
+const HISTORY_LIMIT: usize = 100;
 pub struct PlaylistManager {
     pub playlist: Mutex<VecDeque<MusicInfo>>,     // 播放列表
     pub current_index: Mutex<Option<usize>>,      // 当前播放索引
     pub play_mode: RwLock<PlayMode>,              // 播放模式
     pub shuffle_order: Mutex<Option<Vec<usize>>>, // 随机播放顺序
+    pub shuffle_loop: RwLock<bool>,               // 随机播放完一轮后是否重新洗牌继续
+    pub history: Mutex<VecDeque<MusicInfo>>,      // 播放历史，上一首从这里取
+    pub queue: Mutex<UpNextQueue>,                // 待播队列
+    pub playing_queued: Mutex<Option<MusicInfo>>, // 正在播放的待播队列歌曲，此时 current_index 保留播放列表的位置
+    pub added_order: Mutex<HashMap<String, u64>>, // 歌曲加入播放列表的序号，按加入先后排序时使用
+    added_counter: AtomicU64,                     // 下一首加入的歌曲的序号
 }
 impl Default for PlaylistManager {
     fn default() -> Self {
@@ -28,6 +46,12 @@ impl PlaylistManager {
             current_index: Mutex::new(None),
             play_mode: RwLock::new(PlayMode::Normal),
             shuffle_order: Mutex::new(None),
+            shuffle_loop: RwLock::new(true),
+            history: Mutex::new(VecDeque::new()),
+            queue: Mutex::new(UpNextQueue::new()),
+            playing_queued: Mutex::new(None),
+            added_order: Mutex::new(HashMap::new()),
+            added_counter: AtomicU64::new(0),
         }
     }
     /// 检查音乐是否在播放列表中
@@ -38,20 +62,14 @@ impl PlaylistManager {
             .iter()
             .any(|music| music.bvid == bvid)
     }
-    /// 获取音乐信息
-    pub async fn fetch_music_info(&self, bvid: &str) -> PlayerResult<MusicInfo> {
-        // 实现获取音乐信息的逻辑
-        let client = reqwest::Client::new();
-        let video_data = fetch_video_data(&client, bvid).await?;
-        let music_info = MusicInfo {
-            bvid: video_data.bvid,
-            cid: video_data.cid.to_string(),
-            title: video_data.title,
-            artist: None,
-            owner: video_data.owner.name,
-            duration: 0,
-        };
-        Ok(music_info)
+    /// 获取音乐信息，使用共享的客户端以携带登录 Cookie
+    pub async fn fetch_music_info(
+        &self,
+        client: &reqwest::Client,
+        bvid: &str,
+    ) -> PlayerResult<MusicInfo> {
+        let video_data = fetch_video_data(client, bvid).await?;
+        Ok(MusicInfo::from(video_data))
     }
     /// 获取播放列表长度
     pub async fn get_playlist_len(&self) -> usize {
@@ -74,6 +92,25 @@ impl PlaylistManager {
             .cloned() // 因为 iter() 返回 &T，需要 clone 成 T
             .collect()
     }
+    /// 按查找条件搜索播放列表，返回 (索引, 音乐信息)，按匹配得分从高到低排列
+    pub async fn find_music(&self, query: &MusicQuery) -> Vec<(usize, MusicInfo)> {
+        let playlist = self.playlist.lock().await;
+        let mut matched: Vec<(u32, usize, MusicInfo)> = playlist
+            .iter()
+            .enumerate()
+            .filter_map(|(index, music)| {
+                query
+                    .score(music)
+                    .map(|score| (score, index, music.clone()))
+            })
+            .collect();
+        // 得分相同时按播放列表顺序
+        matched.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        matched
+            .into_iter()
+            .map(|(_, index, music)| (index, music))
+            .collect()
+    }
     /// 获取音乐索引
     pub async fn get_music_index(&self, bvid: &str) -> Option<usize> {
         self.playlist
@@ -86,42 +123,37 @@ impl PlaylistManager {
     pub async fn get_current_index(&self) -> Option<usize> {
         *self.current_index.lock().await
     }
-    pub async fn add_will_play_music_into_playlist(&self, bvid: &str) -> PlayerResult<()> {
-        if self.is_in_playlist(bvid).await {
-            // 获取这个音乐在列表中的索引
-            let music_index = self.get_music_index(bvid).await.unwrap_or(0);
+    pub async fn add_will_play_music_into_playlist(
+        &self,
+        client: &reqwest::Client,
+        bvid: &str,
+    ) -> PlayerResult<()> {
+        if let Some(music_index) = self.get_music_index(bvid).await {
             // 设置当前播放为这个音乐
-            {
-                let mut current_index = self.current_index.lock().await;
-                *current_index = Some(music_index);
-            } // 🔓 current_index 锁释放
-            return Ok(());
+            return self.set_current_index(music_index).await;
         }
-        let music_info = self.fetch_music_info(bvid).await?;
-        {
+        let music_info = self.fetch_music_info(client, bvid).await?;
+        self.record_added(&music_info.bvid).await;
+        let music_index = {
             let mut playlist = self.playlist.lock().await;
             playlist.push_back(music_info);
-            playlist.len()
+            playlist.len() - 1
         }; // 🔓 playlist 锁在这里释放
-        // 获取这个音乐在列表中的索引
-        let music_index = self.get_music_index(bvid).await.unwrap_or(0);
-        // 设置当前播放为这个音乐
-        {
-            let mut current_index = self.current_index.lock().await;
-            *current_index = Some(music_index);
-        } // 🔓 current_index 锁释放
 
-        // 重置随机播放顺序
-        self.update_shuffle_order().await;
-        Ok(())
+        // 插入到随机播放顺序中当前歌曲之后，接下来就播放它
+        self.insert_into_shuffle_order(music_index, true).await;
+        // 设置当前播放为这个音乐
+        self.set_current_index(music_index).await
     }
     /// 添加音乐到播放列表
     pub async fn add_music(&self, music: MusicInfo) {
+        self.record_added(&music.bvid).await;
         let new_len = {
             let mut playlist = self.playlist.lock().await;
             playlist.push_back(music);
             playlist.len()
         }; // 🔓 playlist 锁在这里释放
+        let new_index = new_len - 1;
 
         // 如果当前没有选中的音乐，选择第一个
         {
@@ -131,39 +163,132 @@ impl PlaylistManager {
             }
         } // 🔓 current_index 锁释放
 
-        // 重置随机播放顺序
-        self.update_shuffle_order().await;
+        // 随机插入到还没播放的部分，不打乱已经安排好的顺序
+        self.insert_into_shuffle_order(new_index, false).await;
     }
     /// 从播放列表中移除音乐
     pub async fn remove_music(&self, index: usize) -> PlayerResult<()> {
+        self.remove_range(index..index + 1).await?;
+        Ok(())
+    }
+    /// 从播放列表中移除一段连续的音乐，返回被移除的音乐
+    pub async fn remove_range(&self, range: Range<usize>) -> PlayerResult<Vec<MusicInfo>> {
         // 判断是否越界
-        {
+        let removed: Vec<MusicInfo> = {
             let mut playlist = self.playlist.lock().await;
-            if index >= playlist.len() {
+            if range.is_empty() || range.end > playlist.len() {
                 return Err(PlayerError::Playlist("Index out of bounds".into()));
             }
-            playlist.remove(index);
-        }
+            playlist.drain(range.clone()).collect()
+        };
 
         // 更新当前索引
-        self.adjust_current_index_after_removal(index).await;
+        self.adjust_current_index_after_removal(range.clone()).await;
 
-        // 重置随机播放顺序
-        self.update_shuffle_order().await;
+        // 从随机播放顺序中去掉被删除的歌曲
+        self.adjust_shuffle_order_after_removal(range).await;
+
+        // 被删除的歌曲也不再留在待播队列中
+        let mut queue = self.queue.lock().await;
+        for music in &removed {
+            queue.remove(&music.bvid);
+        }
 
+        Ok(removed)
+    }
+    /// 移除包含当前歌曲的一段音乐，并按播放模式把当前索引移到区间外的下一首
+    ///
+    /// 只在播放列表中查找下一首，不会取出待播队列中的歌曲，也不会把没有播放过的歌曲记入播放历史。
+    /// 返回被移除的音乐和是否找到了下一首，没有下一首时当前索引指向区间之后的歌曲。
+    pub async fn remove_current_range(
+        &self,
+        range: Range<usize>,
+    ) -> PlayerResult<(Vec<MusicInfo>, bool)> {
+        let len = self.get_playlist_len().await;
+        if range.is_empty() || range.end > len {
+            return Err(PlayerError::Playlist("Index out of bounds".into()));
+        }
+        let mut next_bvid = None;
+        for _ in 0..len {
+            if !self.step_forward().await? {
+                break;
+            }
+            match self.get_current_index().await {
+                Some(index) if range.contains(&index) => continue,
+                _ => {
+                    next_bvid = self.get_cursor_music().await.map(|music| music.bvid);
+                    break;
+                }
+            }
+        }
+        let removed = self.remove_range(range).await?;
+        let next_index = match next_bvid {
+            Some(bvid) => self.find_music_index(&bvid).await,
+            None => None,
+        };
+        // remove_range 之后当前索引指向的歌曲并没有播放过，直接移动索引，不记入播放历史
+        if let Some(index) = next_index {
+            *self.current_index.lock().await = Some(index);
+        }
+        Ok((removed, next_index.is_some()))
+    }
+    /// 设置当前播放索引
+    pub async fn set_current_index(&self, index: usize) -> PlayerResult<()> {
+        let len = self.get_playlist_len().await;
+        if index >= len {
+            return Err(PlayerError::InvalidIndex(format!("{}/{}", index + 1, len)));
+        }
+        let previous = self.get_current_music().await;
+        let was_queued = self.playing_queued.lock().await.take().is_some();
+        let changed = self.current_index.lock().await.replace(index) != Some(index) || was_queued;
+        // 切换到其他歌曲时记录播放历史
+        if changed && let Some(music) = previous {
+            self.push_history(music).await;
+        }
         Ok(())
     }
     /// 获取当前播放的音乐信息
     pub async fn get_current_music(&self) -> Option<MusicInfo> {
-        let current_index = self.current_index.lock().await;
-        let playlist = self.playlist.lock().await;
-        // 只有当 current_index 是 Some(idx) 时，才会执行闭包，否则返回 None
-        current_index.and_then(|idx| playlist.get(idx).cloned())
+        // 正在播放待播队列中的歌曲
+        if let Some(music) = self.playing_queued.lock().await.clone() {
+            return Some(music);
+        }
+        self.get_cursor_music().await
     }
-    /// 下一首
+    /// 下一首，返回 false 表示已经到达列表末尾
+    ///
+    /// 顺序播放到最后一首时停止；列表循环和单曲循环回到第一首；
+    /// 随机播放完一轮后，开启随机循环时重新洗牌继续，否则停止。
     pub async fn move_to_next(&self) -> PlayerResult<bool> {
+        let previous = self.get_current_music().await;
+        // 除单曲循环外，待播队列优先于播放列表
+        let queued = if self.get_play_mode().await == PlayMode::Repeat {
+            None
+        } else {
+            self.queue.lock().await.pop()
+        };
+        let moved = match queued {
+            Some(music) => {
+                *self.playing_queued.lock().await = Some(music);
+                true
+            }
+            // 待播队列播完后从播放列表原来的位置继续
+            None => {
+                self.playing_queued.lock().await.take();
+                self.step_forward().await?
+            }
+        };
+        // 记录播放历史，供上一首使用
+        if moved && let Some(music) = previous {
+            self.push_history(music).await;
+        }
+        Ok(moved)
+    }
+    /// 按播放模式把当前索引向后移动一首
+    async fn step_forward(&self) -> PlayerResult<bool> {
         // 获取当前播放模式
         let play_mode = self.get_play_mode().await;
+        let shuffle_loop = self.get_shuffle_loop().await;
         // 获取当前播放索引
         let mut current_index = self.current_index.lock().await;
         // 获取当前播放列表
@@ -172,84 +297,238 @@ impl PlaylistManager {
         if playlist.is_empty() {
             return Ok(false);
         }
+        let Some(idx) = *current_index else {
+            *current_index = Some(0);
+            return Ok(true);
+        };
 
         match play_mode {
-            // // 如果是单曲播放模式
-            // PlayMode::Repeat => Ok(true),
-            // 如果是随机播放模/式，随机选择一首
+            // 如果是随机播放模式，按随机顺序选择下一首
             PlayMode::Shuffle => {
-                let shuffle_order = self.shuffle_order.lock().await;
-                if let Some(order) = shuffle_order.as_ref()
-                    && let Some(current_idx) = *current_index
-                    && let Some(pos) = order.iter().position(|&i| i == current_idx)
-                {
-                    let next_pos = (pos + 1) % order.len();
-                    *current_index = Some(order[next_pos]);
-                    return Ok(true);
-                }
-
-                Ok(false)
-            }
-            // 其他就直接下一首
-            _ => {
-                if let Some(idx) = *current_index {
-                    if idx + 1 < playlist.len() {
-                        *current_index = Some(idx + 1);
+                let mut shuffle_order = self.shuffle_order.lock().await;
+                let pos = shuffle_order
+                    .as_ref()
+                    .and_then(|order| order.iter().position(|&i| i == idx));
+                match (shuffle_order.as_mut(), pos) {
+                    (Some(order), Some(pos)) if pos + 1 < order.len() => {
+                        *current_index = Some(order[pos + 1]);
                         Ok(true)
-                    } else if idx == playlist.len() - 1 {
-                        *current_index = Some(0);
+                    }
+                    // 一轮播放完毕
+                    _ if shuffle_loop => {
+                        let order = Self::new_shuffle_order(playlist.len(), Some(idx));
+                        *current_index = Some(order[0]);
+                        *shuffle_order = Some(order);
                         Ok(true)
-                    } else {
-                        Ok(false)
                     }
-                } else {
-                    *current_index = Some(0);
-                    Ok(true)
+                    _ => Ok(false),
                 }
             }
+            // 顺序播放到最后一首时停止
+            PlayMode::Normal if idx + 1 >= playlist.len() => Ok(false),
+            // 其他就直接下一首，到末尾时回到第一首
+            _ => {
+                *current_index = Some((idx + 1) % playlist.len());
+                Ok(true)
+            }
         }
     }
-    /// 上一首
+    /// 上一首，返回 false 表示已经在列表开头
+    ///
+    /// 优先回到播放历史中真正听过的上一首；历史为空时与 `move_to_next` 对应：
+    /// 顺序播放在第一首时不再后退，随机播放只在开启随机循环时回绕。
     pub async fn move_to_previous(&self) -> PlayerResult<bool> {
+        // 正在播放待播队列的歌曲时，播放列表的当前歌曲也算作上一首
+        let was_queued = self.playing_queued.lock().await.take().is_some();
+        while let Some(music) = self.history.lock().await.pop_back() {
+            // 历史中的歌曲可能已经被删除
+            let Some(index) = self.find_music_index(&music.bvid).await else {
+                continue;
+            };
+            let mut current_index = self.current_index.lock().await;
+            if was_queued || *current_index != Some(index) {
+                *current_index = Some(index);
+                return Ok(true);
+            }
+        }
+        self.step_backward().await
+    }
+    /// 播放历史为空时，按播放模式把当前索引向前移动一首
+    async fn step_backward(&self) -> PlayerResult<bool> {
         let play_mode = self.get_play_mode().await;
+        let shuffle_loop = self.get_shuffle_loop().await;
         let mut current_index = self.current_index.lock().await;
         let playlist = self.playlist.lock().await;
 
         if playlist.is_empty() {
             return Ok(false);
         }
+        let Some(idx) = *current_index else {
+            *current_index = Some(0);
+            return Ok(true);
+        };
 
         match play_mode {
             PlayMode::Shuffle => {
                 let shuffle_order = self.shuffle_order.lock().await;
                 if let Some(order) = shuffle_order.as_ref()
-                    && let Some(current_idx) = *current_index
-                    && let Some(pos) = order.iter().position(|&i| i == current_idx)
+                    && let Some(pos) = order.iter().position(|&i| i == idx)
                 {
-                    let prev_pos = if pos == 0 { order.len() - 1 } else { pos - 1 };
-                    *current_index = Some(order[prev_pos]);
-                    return Ok(true);
+                    if pos > 0 {
+                        *current_index = Some(order[pos - 1]);
+                        return Ok(true);
+                    }
+                    if shuffle_loop {
+                        *current_index = Some(order[order.len() - 1]);
+                        return Ok(true);
+                    }
                 }
 
                 Ok(false)
             }
+            PlayMode::Normal if idx == 0 => Ok(false),
             _ => {
-                if let Some(idx) = *current_index {
-                    if idx > 0 {
-                        *current_index = Some(idx - 1);
-                        Ok(true)
-                    } else if idx == 0 {
-                        *current_index = Some(playlist.len() - 1);
-                        Ok(true)
-                    } else {
-                        Ok(false)
-                    }
+                *current_index = Some((idx + playlist.len() - 1) % playlist.len());
+                Ok(true)
+            }
+        }
+    }
+    /// 用另一个歌单的歌曲替换整个播放列表，并把当前歌曲设为 current
+    pub async fn replace_playlist(&self, musics: Vec<MusicInfo>, current: Option<usize>) {
+        // 新歌单按原来的顺序作为加入顺序
+        self.added_order.lock().await.clear();
+        for music in &musics {
+            self.record_added(&music.bvid).await;
+        }
+        {
+            let mut current_index = self.current_index.lock().await;
+            let mut playlist = self.playlist.lock().await;
+            *current_index = match current {
+                Some(index) if index < musics.len() => Some(index),
+                _ if musics.is_empty() => None,
+                _ => Some(0),
+            };
+            *playlist = musics.into();
+        } // 🔓 current_index、playlist 锁释放
+        self.playing_queued.lock().await.take();
+        // 重置随机播放顺序
+        self.update_shuffle_order().await;
+    }
+    /// 把 from 位置的歌曲移动到 to 位置，中间的歌曲依次挪动
+    pub async fn move_music(&self, from: usize, to: usize) -> PlayerResult<()> {
+        self.reorder_with(|playlist| {
+            let len = playlist.len();
+            Self::check_index(from, len)?;
+            Self::check_index(to, len)?;
+            let mut order: Vec<usize> = (0..len).collect();
+            let index = order.remove(from);
+            order.insert(to, index);
+            Ok(order)
+        })
+        .await
+    }
+    /// 把歌曲移动到播放列表最前面
+    pub async fn move_to_top(&self, index: usize) -> PlayerResult<()> {
+        self.move_music(index, 0).await
+    }
+    /// 交换两首歌的位置
+    pub async fn swap_music(&self, first: usize, second: usize) -> PlayerResult<()> {
+        self.reorder_with(|playlist| {
+            let len = playlist.len();
+            Self::check_index(first, len)?;
+            Self::check_index(second, len)?;
+            let mut order: Vec<usize> = (0..len).collect();
+            order.swap(first, second);
+            Ok(order)
+        })
+        .await
+    }
+    /// 按指定依据对播放列表排序，依据相同的歌曲保持原来的先后顺序
+    pub async fn sort_by(&self, key: SortKey, descending: bool) -> PlayerResult<()> {
+        let added = self.added_order.lock().await.clone();
+        self.reorder_with(|playlist| {
+            let mut order: Vec<usize> = (0..playlist.len()).collect();
+            order.sort_by(|&a, &b| {
+                let ordering = key.compare(&playlist[a], &playlist[b], &added);
+                if descending {
+                    ordering.reverse()
                 } else {
-                    *current_index = Some(0);
-                    Ok(true)
+                    ordering
                 }
+            });
+            Ok(order)
+        })
+        .await
+    }
+    /// 执行一次调整顺序的操作
+    pub async fn reorder(&self, op: Reorder) -> PlayerResult<()> {
+        match op {
+            Reorder::Move { from, to } => self.move_music(from, to).await,
+            Reorder::MoveToTop(index) => self.move_to_top(index).await,
+            Reorder::Swap(first, second) => self.swap_music(first, second).await,
+            Reorder::Sort { key, descending } => self.sort_by(key, descending).await,
+        }
+    }
+    /// 按 make_order 给出的新顺序重排播放列表，order[新位置] = 原位置
+    ///
+    /// 当前索引和随机播放顺序随歌曲一起移动，所以正在播放的歌曲和随机播放的先后都不变。
+    async fn reorder_with<F>(&self, make_order: F) -> PlayerResult<()>
+    where
+        F: FnOnce(&VecDeque<MusicInfo>) -> PlayerResult<Vec<usize>>,
+    {
+        let mut current_index = self.current_index.lock().await;
+        let mut playlist = self.playlist.lock().await;
+        let mut shuffle_order = self.shuffle_order.lock().await;
+        let order = make_order(&playlist)?;
+        // 原位置 -> 新位置
+        let mut new_positions = vec![0; order.len()];
+        for (new, &old) in order.iter().enumerate() {
+            new_positions[old] = new;
+        }
+        *playlist = order.iter().map(|&old| playlist[old].clone()).collect();
+        if let Some(idx) = current_index.as_mut() {
+            *idx = new_positions[*idx];
+        }
+        if let Some(shuffle) = shuffle_order.as_mut() {
+            for idx in shuffle.iter_mut() {
+                *idx = new_positions[*idx];
             }
         }
+        Ok(())
+    }
+    /// 检查索引是否在播放列表范围内
+    fn check_index(index: usize, len: usize) -> PlayerResult<()> {
+        if index >= len {
+            return Err(PlayerError::InvalidIndex(format!("{}/{}", index + 1, len)));
+        }
+        Ok(())
+    }
+    /// 记录歌曲加入播放列表的序号，重新加入的歌曲算作最新加入
+    async fn record_added(&self, bvid: &str) {
+        let seq = self.added_counter.fetch_add(1, Ordering::Relaxed);
+        self.added_order.lock().await.insert(bvid.to_string(), seq);
+    }
+    /// 获取播放列表中当前位置的歌曲，不包括正在播放的待播队列歌曲
+    pub async fn get_cursor_music(&self) -> Option<MusicInfo> {
+        let current_index = self.current_index.lock().await;
+        let playlist = self.playlist.lock().await;
+        current_index.and_then(|idx| playlist.get(idx).cloned())
+    }
+    /// 把歌曲加入待播队列，返回歌曲在队列中的索引
+    pub async fn queue_music(&self, music: MusicInfo, position: QueuePosition) -> usize {
+        self.queue.lock().await.push(music, position)
+    }
+    /// 清空待播队列，返回被清除的歌曲数
+    pub async fn clear_queue(&self) -> usize {
+        self.queue.lock().await.clear()
+    }
+    /// 获取待播队列中的全部歌曲
+    pub async fn get_queue(&self) -> Vec<MusicInfo> {
+        self.queue.lock().await.list()
+    }
+    /// 是否正在播放待播队列中的歌曲
+    pub async fn is_playing_queued(&self) -> bool {
+        self.playing_queued.lock().await.is_some()
     }
     /// 设置播放模式
     pub async fn set_play_mode(&self, mode: PlayMode) {
@@ -268,8 +547,17 @@ impl PlaylistManager {
     pub async fn get_play_mode(&self) -> PlayMode {
         *self.play_mode.read().await
     }
+    /// 设置随机播放完一轮后是否继续
+    pub async fn set_shuffle_loop(&self, shuffle_loop: bool) {
+        *self.shuffle_loop.write().await = shuffle_loop;
+    }
+    /// 获取随机播放完一轮后是否继续
+    pub async fn get_shuffle_loop(&self) -> bool {
+        *self.shuffle_loop.read().await
+    }
     /// 更新播放顺序
     async fn update_shuffle_order(&self) {
+        let current = *self.current_index.lock().await;
         let len = {
             let playlist = self.playlist.lock().await;
             playlist.len()
@@ -279,28 +567,88 @@ impl PlaylistManager {
             *shuffle_order = None;
             return; // 🔓 shuffle_order 锁释放
         }
+        let mut order = Self::new_shuffle_order(len, None);
+        // 从当前歌曲开始新一轮随机播放
+        if let Some(pos) = current.and_then(|current| order.iter().position(|&i| i == current)) {
+            let current = order.remove(pos);
+            order.insert(0, current);
+        }
+        *shuffle_order = Some(order);
+    }
+    /// 把新加入的歌曲插入随机播放顺序：immediately 为 true 时紧跟在当前歌曲之后，
+    /// 否则随机插入到当前歌曲之后的剩余部分
+    async fn insert_into_shuffle_order(&self, index: usize, immediately: bool) {
+        let current = *self.current_index.lock().await;
+        let mut shuffle_order = self.shuffle_order.lock().await;
+        let Some(order) = shuffle_order.as_mut() else {
+            drop(shuffle_order);
+            self.update_shuffle_order().await;
+            return;
+        };
+        // 当前歌曲之后的第一个位置
+        let start = current
+            .and_then(|current| order.iter().position(|&i| i == current))
+            .map_or(0, |pos| pos + 1);
+        let pos = if immediately {
+            start
+        } else {
+            rand::rng().random_range(start..=order.len())
+        };
+        order.insert(pos, index);
+    }
+    /// 删除歌曲后更新随机播放顺序：去掉被删除的索引，后面的索引依次前移
+    async fn adjust_shuffle_order_after_removal(&self, removed: Range<usize>) {
+        let mut shuffle_order = self.shuffle_order.lock().await;
+        if let Some(order) = shuffle_order.as_mut() {
+            order.retain(|i| !removed.contains(i));
+            for i in order.iter_mut() {
+                if *i >= removed.end {
+                    *i -= removed.len();
+                }
+            }
+            if order.is_empty() {
+                *shuffle_order = None;
+            }
+        }
+    }
+    /// 记录播放历史，超过上限时丢弃最早的记录
+    async fn push_history(&self, music: MusicInfo) {
+        let mut history = self.history.lock().await;
+        if history.back().is_some_and(|last| last.bvid == music.bvid) {
+            return;
+        }
+        history.push_back(music);
+        if history.len() > HISTORY_LIMIT {
+            history.pop_front();
+        }
+    }
+    /// 生成新的随机播放顺序，尽量不让上一轮的最后一首紧接着再播一次
+    fn new_shuffle_order(len: usize, last: Option<usize>) -> Vec<usize> {
         let mut order: Vec<usize> = (0..len).collect();
         order.shuffle(&mut rand::rng());
-        *shuffle_order = Some(order);
+        if len > 1 && order.first() == last.as_ref() {
+            order.swap(0, len - 1);
+        }
+        order
     }
     /// 调整当前索引以适应删除操作
-    async fn adjust_current_index_after_removal(&self, removed_index: usize) {
+    async fn adjust_current_index_after_removal(&self, removed: Range<usize>) {
         // 获取 playlist 和 current index
         let playlist = self.playlist.lock().await;
         let mut current_index = self.current_index.lock().await;
         // 更新当前索引
         match *current_index {
-            Some(idx) if idx == removed_index => {
-                // 当前播放的歌曲被删除
+            Some(idx) if removed.contains(&idx) => {
+                // 当前播放的歌曲被删除，指向被删除区间之后的歌曲
                 if playlist.is_empty() {
                     *current_index = None;
-                } else if idx >= playlist.len() {
-                    *current_index = Some(playlist.len() - 1);
+                } else {
+                    *current_index = Some(removed.start.min(playlist.len() - 1));
                 }
             }
-            Some(idx) if idx > removed_index => {
+            Some(idx) if idx >= removed.end => {
                 // 当前播放的歌曲在删除的歌曲之后
-                *current_index = Some(idx - 1);
+                *current_index = Some(idx - removed.len());
             }
             _ => {}
         }
@@ -0,0 +1,52 @@
+use std::collections::VecDeque;
+
+use crate::player::model::MusicInfo;
+
+/// 插入到待播队列的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueuePosition {
+    Next, // 插到队首，当前歌曲结束后立即播放
+    Last, // 追加到队尾
+}
+
+/// 待播队列，独立于播放列表，按顺序优先于播放列表播放
+#[derive(Debug, Default)]
+pub struct UpNextQueue {
+    musics: VecDeque<MusicInfo>,
+}
+impl UpNextQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// 把歌曲加入队列，返回歌曲在队列中的索引
+    pub fn push(&mut self, music: MusicInfo, position: QueuePosition) -> usize {
+        match position {
+            QueuePosition::Next => {
+                self.musics.push_front(music);
+                0
+            }
+            QueuePosition::Last => {
+                self.musics.push_back(music);
+                self.musics.len() - 1
+            }
+        }
+    }
+    /// 取出下一首待播歌曲
+    pub fn pop(&mut self) -> Option<MusicInfo> {
+        self.musics.pop_front()
+    }
+    /// 清空队列，返回被清除的歌曲数
+    pub fn clear(&mut self) -> usize {
+        let len = self.musics.len();
+        self.musics.clear();
+        len
+    }
+    /// 从队列中移除指定 bvid 的歌曲
+    pub fn remove(&mut self, bvid: &str) {
+        self.musics.retain(|music| music.bvid != bvid);
+    }
+    /// 队列中的全部歌曲
+    pub fn list(&self) -> Vec<MusicInfo> {
+        self.musics.iter().cloned().collect()
+    }
+}
@@ -0,0 +1,89 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::player::model::MusicInfo;
+
+/// 播放列表排序依据
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Title,    // 标题
+    Artist,   // 歌手，没有歌手的排在最后
+    Owner,    // UP 主
+    Duration, // 时长
+    Added,    // 加入播放列表的先后
+}
+impl SortKey {
+    pub fn get_string(&self) -> String {
+        match self {
+            SortKey::Title => "标题".to_string(),
+            SortKey::Artist => "歌手".to_string(),
+            SortKey::Owner => "UP 主".to_string(),
+            SortKey::Duration => "时长".to_string(),
+            SortKey::Added => "加入顺序".to_string(),
+        }
+    }
+    /// 从字符串解析排序依据，无法识别时返回 None
+    pub fn from_string(s: &str) -> Option<SortKey> {
+        match s.trim().to_lowercase().as_str() {
+            "title" => Some(SortKey::Title),
+            "artist" => Some(SortKey::Artist),
+            "owner" => Some(SortKey::Owner),
+            "duration" => Some(SortKey::Duration),
+            "added" => Some(SortKey::Added),
+            _ => None,
+        }
+    }
+    /// 按排序依据比较两首歌，added 为歌曲加入播放列表的序号
+    pub fn compare(&self, a: &MusicInfo, b: &MusicInfo, added: &HashMap<String, u64>) -> Ordering {
+        match self {
+            SortKey::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortKey::Artist => {
+                let key = |m: &MusicInfo| {
+                    (
+                        m.artist.is_none(),
+                        m.artist.as_deref().map(str::to_lowercase),
+                    )
+                };
+                key(a).cmp(&key(b))
+            }
+            SortKey::Owner => a.owner.to_lowercase().cmp(&b.owner.to_lowercase()),
+            SortKey::Duration => a.duration.cmp(&b.duration),
+            SortKey::Added => {
+                let seq = |m: &MusicInfo| added.get(&m.bvid).copied().unwrap_or(u64::MAX);
+                seq(a).cmp(&seq(b))
+            }
+        }
+    }
+}
+/// 转换为 gRPC 中的排序依据
+impl From<SortKey> for crate::pb::SortKey {
+    fn from(key: SortKey) -> Self {
+        match key {
+            SortKey::Title => Self::Title,
+            SortKey::Artist => Self::Artist,
+            SortKey::Owner => Self::Owner,
+            SortKey::Duration => Self::Duration,
+            SortKey::Added => Self::Added,
+        }
+    }
+}
+/// 从 gRPC 中的排序依据转换
+impl From<crate::pb::SortKey> for SortKey {
+    fn from(key: crate::pb::SortKey) -> Self {
+        match key {
+            crate::pb::SortKey::Title => Self::Title,
+            crate::pb::SortKey::Artist => Self::Artist,
+            crate::pb::SortKey::Owner => Self::Owner,
+            crate::pb::SortKey::Duration => Self::Duration,
+            crate::pb::SortKey::Added => Self::Added,
+        }
+    }
+}
+
+/// 调整播放列表顺序的操作，索引从 0 开始
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reorder {
+    Move { from: usize, to: usize },         // 把一首歌移动到指定位置
+    MoveToTop(usize),                        // 把一首歌移动到最前面
+    Swap(usize, usize),                      // 交换两首歌
+    Sort { key: SortKey, descending: bool }, // 按指定依据排序，相同时保持原来的顺序
+}
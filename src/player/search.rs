@@ -0,0 +1,67 @@
+use crate::player::model::MusicInfo;
+
+/// 查找条件，为 None 的字段不参与匹配
+#[derive(Debug, Clone, Default)]
+pub struct MusicQuery {
+    pub bvid: Option<String>,
+    pub cid: Option<String>,
+    pub title: Option<String>,
+    pub owner: Option<String>,
+}
+impl MusicQuery {
+    /// 是否没有任何查找条件
+    pub fn is_empty(&self) -> bool {
+        [&self.bvid, &self.cid, &self.title, &self.owner]
+            .iter()
+            .all(|field| field.is_none())
+    }
+    /// 计算音乐与查找条件的匹配得分，任一条件不匹配时返回 None
+    pub fn score(&self, music: &MusicInfo) -> Option<u32> {
+        let conditions = [
+            (&self.bvid, music.bvid.as_str()),
+            (&self.cid, music.cid.as_str()),
+            (&self.title, music.title.as_str()),
+            (&self.owner, music.owner.as_str()),
+        ];
+        let mut total = 0;
+        for (needle, haystack) in conditions {
+            if let Some(needle) = needle {
+                total += match_score(haystack, needle)?;
+            }
+        }
+        Some(total)
+    }
+}
+
+/// 大小写不敏感地匹配单个字段，返回匹配得分
+///
+/// 完全相等 > 前缀 > 子串 > 模糊匹配（按顺序包含查找词的所有字符，间隔越小得分越高）
+pub fn match_score(haystack: &str, needle: &str) -> Option<u32> {
+    let haystack = haystack.to_lowercase();
+    let needle = needle.trim().to_lowercase();
+    if needle.is_empty() {
+        return Some(0);
+    }
+    if haystack == needle {
+        return Some(100);
+    }
+    if haystack.starts_with(&needle) {
+        return Some(80);
+    }
+    if haystack.contains(&needle) {
+        return Some(60);
+    }
+    // 模糊匹配：统计字符之间跳过的字符数
+    let mut gaps = 0;
+    let mut chars = haystack.chars();
+    for expected in needle.chars() {
+        loop {
+            let c = chars.next()?;
+            if c == expected {
+                break;
+            }
+            gaps += 1;
+        }
+    }
+    Some(40u32.saturating_sub(gaps).max(1))
+}
@@ -1,6 +1,9 @@
 use gstreamer::format::FormattedValue;
 
-use crate::player::{model::MusicInfo, play_mode::PlayMode, playback::PlaybackState};
+use crate::{
+    fetch::stream::AudioQuality,
+    player::{model::MusicInfo, play_mode::PlayMode, playback::PlaybackState},
+};
 
 #[derive(Debug, Clone)]
 pub struct PlayerState {
@@ -12,66 +15,34 @@ pub struct PlayerState {
     pub play_mode: PlayMode,
     pub playlist_length: usize,
     pub current_index: Option<usize>,
+    pub audio_quality: Option<AudioQuality>, // 当前音轨的音质，没有播放时为 None
 }
 
-impl std::fmt::Display for PlayerState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let music_title = self
-            .current_music
-            .as_ref()
-            .map(|music| music.title.clone())
-            .unwrap_or_else(|| "None".to_string());
-        let music_bvid = self
-            .current_music
-            .as_ref()
-            .map(|music| music.bvid.clone())
-            .unwrap_or_else(|| "None".to_string());
-        let music_cid = self
-            .current_music
-            .as_ref()
-            .map(|music| music.cid.clone())
-            .unwrap_or_else(|| "None".to_string());
-        let music_artist = self
-            .current_music
-            .as_ref()
-            .and_then(|music| music.artist.clone()) // 如果 artist 是 Some，克隆出 String；否则 None
-            .unwrap_or_else(|| "Unknown".to_string());
-        let music_upper = self
-            .current_music
-            .as_ref()
-            .map(|music| music.owner.clone())
-            .unwrap_or_else(|| "None".to_string());
-        let current_index = match self.current_index {
-            Some(idx) => idx + 1,
-            None => 0,
-        };
-        let duration = format_clock_time(self.duration);
-        let music_info = format!(
-            "《{}》({}) bvid: {} cid: {} 演唱: {} 上传者: {}",
-            music_title, duration, music_bvid, music_cid, music_artist, music_upper
-        );
-        let music_info_show = format!(
-            "《{}》-{}-{}-{}-{}-{}",
-            music_title, duration, music_bvid, music_cid, music_artist, music_upper
-        );
-        write!(
-            f,
-            "{}:《{}》时长:{}/{}, 音量:{}, 播放模式:{}, 第{}个/共{}个。\n当前播放:{}\n保存：{}",
-            self.playback_state.show_info(),
-            music_title,
-            format_clock_time(self.current_position),
-            duration,
-            self.volume,
-            self.play_mode.get_string(),
-            current_index,
-            self.playlist_length,
-            music_info,
-            music_info_show
-        )
+/// 转换为 gRPC 中的播放器状态
+impl From<PlayerState> for crate::pb::GetStateResponse {
+    fn from(state: PlayerState) -> Self {
+        // 时间未知时 gstreamer 返回 None，对应字段不设置
+        let known_ms =
+            |time: Option<gstreamer::ClockTime>| time.filter(|t| t.is_some()).map(|t| t.mseconds());
+        Self {
+            success: true,
+            state: crate::pb::PlaybackState::from(state.playback_state) as i32,
+            position_ms: known_ms(state.current_position),
+            duration_ms: known_ms(state.duration),
+            volume: state.volume,
+            mode: crate::pb::PlayMode::from(state.play_mode) as i32,
+            index: state.current_index.map(|index| index as u32 + 1),
+            playlist_length: state.playlist_length as u32,
+            music: state.current_music.map(Into::into),
+            audio_quality: state
+                .audio_quality
+                .map(|quality| crate::pb::AudioQuality::from(quality) as i32),
+        }
     }
 }
 
-fn format_clock_time(time: Option<gstreamer::ClockTime>) -> String {
+/// 把时间格式化为 mm:ss
+pub fn format_clock_time(time: Option<gstreamer::ClockTime>) -> String {
     match time {
         Some(t) if t.is_some() => {
             let secs = t.seconds(); // 安全：只有 is_some() 为 true 时才调用
@@ -1,4 +1,7 @@
-use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
 
 use gstreamer::{glib::object::ObjectExt, prelude::GstBinExt};
 
@@ -95,6 +98,18 @@ impl VolumeManager {
             Ok(true)
         }
     }
+    /// 在 duration 内把音量逐渐降到 0，不改变保存的音量百分比
+    pub async fn fade_out(&self, pipeline: &gstreamer::Pipeline, duration: Duration) {
+        const STEPS: u32 = 10;
+        let volume = self.get_gstreamer_volume();
+        for step in (0..STEPS).rev() {
+            if let Err(e) = self.apply_volume(pipeline, volume * step as f64 / STEPS as f64) {
+                tracing::warn!("Failed to fade out: {}", e);
+                return;
+            }
+            tokio::time::sleep(duration / STEPS).await;
+        }
+    }
     /// 是否为静音
     pub fn is_muted(&self, pipeline: &gstreamer::Pipeline) -> bool {
         if let Some(volume_elem) = pipeline.by_name("audio_volume") {
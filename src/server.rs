@@ -1,44 +1,287 @@
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+
 use bili_player::{
-    logger::init_logger,
+    auth::{Authenticator, record_grpc_path},
+    config::{Config, ServerConfig},
+    fetch::{
+        credentials::{CredentialStore, Credentials},
+        network::fetch_video_data,
+        stream::AudioPreference,
+    },
+    library::{self, importer::import_music_file, repository::MusicRepository},
+    logger::init_logger_with_file,
     pb::{
-        AddPlaylistRequest, AddPlaylistResponse, DeletedRequest, DeletedResponse, GetStateRequest,
-        GetStateResponse, NextRequest, NextResponse, PauseRequest, PauseResponse, PlayBvidRequest,
-        PlayBvidResponse, PlayRequest, PlayResponse, PreviousRequest, PreviousResponse,
-        ResumeRequest, ResumeResponse, SeekRequest, SeekResponse, SetModelRequest,
-        SetModelResponse, SetVolumeRequest, SetVolumeResponse, ShowMusicPageInfoRequest,
-        ShowMusicPageInfoResponse, StopRequest, StopResponse,
+        AddPlaylistRequest, AddPlaylistResponse, AddToPlaylistRequest, AddToPlaylistResponse,
+        ClearQueueRequest, ClearQueueResponse, CreatePlaylistRequest, CreatePlaylistResponse,
+        DeletePlaylistRequest, DeletePlaylistResponse, DeletedRequest, DeletedResponse,
+        FindRequest, FindResponse, FoundMusic, GetStateRequest, GetStateResponse,
+        ListPlaylistsRequest, ListPlaylistsResponse, ListQueueRequest, ListQueueResponse,
+        LoginStatusRequest, LoginStatusResponse, MoveTrackRequest, MoveTrackResponse, NextRequest,
+        NextResponse, PauseRequest, PauseResponse, PlayBvidRequest, PlayBvidResponse, PlayRequest,
+        PlayResponse, PreviousRequest, PreviousResponse, QueueLastRequest, QueueLastResponse,
+        QueueNextRequest, QueueNextResponse, RemoveFromPlaylistRequest, RemoveFromPlaylistResponse,
+        RenamePlaylistRequest, RenamePlaylistResponse, ResumeRequest, ResumeResponse, SeekMode,
+        SeekRequest, SeekResponse, SetModelRequest, SetModelResponse, SetVolumeRequest,
+        SetVolumeResponse, ShowMusicPageInfoRequest, ShowMusicPageInfoResponse,
+        SortPlaylistRequest, SortPlaylistResponse, StopRequest, StopResponse, SubscribeRequest,
+        SwapTracksRequest, SwapTracksResponse, SwitchPlaylistRequest, SwitchPlaylistResponse,
+        UpdateCredentialsRequest, UpdateCredentialsResponse,
         player_service_server::{PlayerService, PlayerServiceServer},
     },
     player::{
-        audio_player::AudioPlayer, command::PlayerCommand, play_mode::PlayMode, state::PlayerState,
+        audio_player::AudioPlayer,
+        command::{PlayerCommand, Responder},
+        model::MusicInfo,
+        play_mode::PlayMode,
+        playback::SeekTarget,
+        queue::QueuePosition,
+        reorder::{Reorder, SortKey},
+        search::MusicQuery,
+        state::{PlayerState, format_clock_time},
     },
 };
-use tokio::sync::{mpsc, oneshot};
+use clap::Parser;
+use futures_util::{Stream, StreamExt, stream};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+#[cfg(unix)]
+use tokio_stream::wrappers::UnixListenerStream;
 use tonic::{Request, Response, Status, transport::Server};
+use tower::util::MapRequestLayer;
+
+#[derive(Debug, Parser)]
+#[command(name = "server", about = "Bilibili player daemon.")]
+struct ServerArgs {
+    #[arg(
+        short = 'i',
+        long = "import",
+        help = "把 musics.txt 格式的文件导入音乐库后退出"
+    )]
+    import: Option<PathBuf>,
+    #[arg(short = 'r', long = "resume", help = "启动后从上次保存的位置继续播放")]
+    resume: bool,
+    #[arg(
+        short = 'c',
+        long = "config",
+        help = "配置文件路径，默认为 $XDG_CONFIG_HOME/bili-player/config.toml"
+    )]
+    config: Option<PathBuf>,
+    #[arg(short = 'l', long = "listen", help = "gRPC 监听地址，例如 [::1]:50052")]
+    listen: Option<String>,
+    #[arg(
+        short = 'u',
+        long = "unix-socket",
+        help = "改为监听 Unix socket，只有当前用户可以访问"
+    )]
+    unix_socket: bool,
+    #[arg(
+        long = "socket-path",
+        help = "Unix socket 路径，默认为 $XDG_RUNTIME_DIR/bili-player/player.sock"
+    )]
+    socket_path: Option<PathBuf>,
+    #[arg(
+        long = "library",
+        help = "音乐库为空时导入的歌曲列表文件，例如 musics.txt"
+    )]
+    library: Option<PathBuf>,
+    #[arg(long = "database-url", env = "DATABASE_URL", help = "数据库地址")]
+    database_url: Option<String>,
+    #[arg(long = "volume", help = "默认音量百分比")]
+    volume: Option<u32>,
+    #[arg(
+        short = 'm',
+        long = "mode",
+        help = "默认播放模式：normal、shuffle、repeat 或 repeat_all"
+    )]
+    mode: Option<String>,
+    #[arg(
+        long = "audio-quality",
+        help = "音质偏好：max、data_saver 或 192k 形式的码率上限，默认为 192k"
+    )]
+    audio_quality: Option<String>,
+    #[arg(long = "cache-dir", help = "缓存目录")]
+    cache_dir: Option<PathBuf>,
+    #[arg(
+        long = "log-level",
+        help = "日志等级：trace、debug、info、warn 或 error"
+    )]
+    log_level: Option<String>,
+    #[arg(long = "log-file", help = "同时把日志写入缓存目录下的日志文件")]
+    log_file: bool,
+    #[arg(
+        long = "cookie-file",
+        help = "登录 Cookie 文件，支持 cookies.txt 格式或 SESSDATA=...; bili_jct=... 形式"
+    )]
+    cookie_file: Option<PathBuf>,
+}
+impl ServerArgs {
+    /// 用命令行参数覆盖配置文件中的设置
+    fn apply(&self, config: &mut ServerConfig) {
+        if let Some(listen) = &self.listen {
+            config.listen = listen.clone();
+        }
+        if self.unix_socket {
+            config.unix_socket = true;
+        }
+        if let Some(socket_path) = &self.socket_path {
+            config.socket_path = Some(socket_path.clone());
+        }
+        if let Some(library) = &self.library {
+            config.library = Some(library.clone());
+        }
+        if let Some(database_url) = &self.database_url {
+            config.database_url = database_url.clone();
+        }
+        if let Some(volume) = self.volume {
+            config.volume = volume;
+        }
+        if let Some(mode) = &self.mode {
+            config.play_mode = mode.clone();
+        }
+        if let Some(audio_quality) = &self.audio_quality {
+            config.audio_quality = audio_quality.clone();
+        }
+        if let Some(cache_dir) = &self.cache_dir {
+            config.cache_dir = cache_dir.clone();
+        }
+        if let Some(log_level) = &self.log_level {
+            config.log.level = log_level.clone();
+        }
+        if self.log_file {
+            config.log.file = true;
+        }
+        if let Some(cookie_file) = &self.cookie_file {
+            config.cookie_file = Some(cookie_file.clone());
+        }
+    }
+}
 
 /// 创建一个结构体，用来实现 rpc 中的 server
 // #[derive(Default)]
 pub struct PlayerServer {
     pub command_sender: mpsc::Sender<PlayerCommand>,
+    pub shutdown: Arc<watch::Sender<bool>>, // 服务是否正在关闭
+    client: reqwest::Client,                // 在命令循环之外请求 B 站接口，与播放器共用登录 Cookie
+    library: MusicRepository,               // 音乐库，已有的歌曲不再请求歌曲信息
+    credentials: CredentialStore,           // 登录凭据，与播放器共用
 }
 impl PlayerServer {
-    pub fn new(command_sender: mpsc::Sender<PlayerCommand>) -> Self {
-        Self { command_sender }
+    pub fn new(
+        command_sender: mpsc::Sender<PlayerCommand>,
+        shutdown: Arc<watch::Sender<bool>>,
+        client: reqwest::Client,
+        library: MusicRepository,
+        credentials: CredentialStore,
+    ) -> Self {
+        Self {
+            command_sender,
+            shutdown,
+            client,
+            library,
+            credentials,
+        }
+    }
+    /// 发送不需要应答的命令
+    async fn send(&self, command: PlayerCommand, error: &'static str) -> Result<(), Status> {
+        self.command_sender
+            .send(command)
+            .await
+            .map_err(|_| Status::internal(error))
+    }
+    /// 发送需要应答的命令并等待播放器返回
+    async fn ask<T>(
+        &self,
+        command: impl FnOnce(oneshot::Sender<T>) -> PlayerCommand,
+        error: &'static str,
+    ) -> Result<T, Status> {
+        // 创建一个 oneshot channel
+        let (sender, receiver) = oneshot::channel();
+        self.send(command(sender), error).await?;
+        // 等待响应
+        receiver.await.map_err(|_| Status::internal(error))
+    }
+    /// 发送需要应答的命令并等待播放器返回处理结果
+    async fn call<T>(
+        &self,
+        command: impl FnOnce(Responder<T>) -> PlayerCommand,
+        error: &'static str,
+    ) -> Result<T, Status> {
+        Ok(self.ask(command, error).await??)
+    }
+    /// 把歌曲加入待播队列，返回歌曲在队列中的索引和歌曲信息
+    async fn queue(
+        &self,
+        bvid: String,
+        position: QueuePosition,
+    ) -> Result<(usize, MusicInfo), Status> {
+        let bvid = bvid.trim();
+        if bvid.is_empty() {
+            return Err(Status::invalid_argument("bvid 不能为空"));
+        }
+        // 音乐库中没有的歌曲先获取歌曲信息，等待 B 站返回期间不阻塞播放控制
+        let music = match self.library.find_by_bvid(bvid).await? {
+            Some(record) => MusicInfo::from(record),
+            None => MusicInfo::from(fetch_video_data(&self.client, bvid).await?),
+        };
+        self.call(
+            |sender| PlayerCommand::Queue {
+                music,
+                position,
+                sender,
+            },
+            "添加到待播队列失败！",
+        )
+        .await
+    }
+    /// 调整播放列表顺序，返回调整后当前歌曲的序号（从 1 开始）
+    async fn reorder(&self, op: Reorder) -> Result<Option<u32>, Status> {
+        let index = self
+            .call(
+                |sender| PlayerCommand::Reorder { op, sender },
+                "调整播放列表顺序失败！",
+            )
+            .await?;
+        Ok(index.map(|index| index as u32 + 1))
     }
 }
+/// 推送给订阅者的事件流
+type PlayerEventStream =
+    Pin<Box<dyn Stream<Item = Result<bili_player::pb::PlayerEvent, Status>> + Send>>;
 /// 实现 PlayerService trait
 #[tonic::async_trait]
 impl PlayerService for PlayerServer {
-    async fn play(&self, _request: Request<PlayRequest>) -> Result<Response<PlayResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Play).await).is_ok() {
+    type SubscribeStream = PlayerEventStream;
+
+    async fn play(&self, request: Request<PlayRequest>) -> Result<Response<PlayResponse>, Status> {
+        let input = request.into_inner();
+        // 指定了序号时跳转到该歌曲播放
+        if input.index > 0 {
+            let music = self
+                .call(
+                    |sender| PlayerCommand::PlayIndex {
+                        index: input.index as usize - 1,
+                        sender,
+                    },
+                    "播放音乐时失败！",
+                )
+                .await?;
             let result = PlayResponse {
                 success: true,
-                message: "音乐正在播放中".into(),
+                message: format!("正在播放第 {} 首: {}", input.index, music.title),
             };
             return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("播放音乐时失败！"))
         }
+        self.send(PlayerCommand::Play, "播放音乐时失败！").await?;
+        let result = PlayResponse {
+            success: true,
+            message: "音乐正在播放中".into(),
+        };
+        Ok(Response::new(result))
     }
 
     async fn play_bvid(
@@ -47,10 +290,8 @@ impl PlayerService for PlayerServer {
     ) -> Result<Response<PlayBvidResponse>, Status> {
         let input = request.into_inner();
         let info = format!("即将播放: {}", input.bvid);
-        let _res = self
-            .command_sender
-            .send(PlayerCommand::PlayBvid(input))
-            .await;
+        self.send(PlayerCommand::PlayBvid(input), "播放音乐时失败！")
+            .await?;
         let result = PlayBvidResponse {
             success: true,
             message: info,
@@ -62,188 +303,725 @@ impl PlayerService for PlayerServer {
         &self,
         _request: Request<PauseRequest>,
     ) -> Result<Response<PauseResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Pause).await).is_ok() {
-            let result = PauseResponse {
-                success: true,
-                message: "暂停播放".into(),
-            };
-            return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("暂停播放时失败！"))
-        }
+        self.send(PlayerCommand::Pause, "暂停播放时失败！").await?;
+        let result = PauseResponse {
+            success: true,
+            message: "暂停播放".into(),
+        };
+        Ok(Response::new(result))
     }
 
     async fn next(&self, _request: Request<NextRequest>) -> Result<Response<NextResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Next).await).is_ok() {
-            let result = NextResponse {
-                success: true,
-                message: "成功切换到下一首歌曲".into(),
-            };
-            return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("切换下一首歌曲时失败！"))
-        }
+        self.send(PlayerCommand::Next, "切换下一首歌曲时失败！")
+            .await?;
+        let result = NextResponse {
+            success: true,
+            message: "成功切换到下一首歌曲".into(),
+        };
+        Ok(Response::new(result))
     }
 
     async fn previous(
         &self,
         _request: Request<PreviousRequest>,
     ) -> Result<Response<PreviousResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Previous).await).is_ok() {
-            let result = PreviousResponse {
-                success: true,
-                message: "成功切换到上一首歌曲".into(),
-            };
-            return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("切换上一首歌曲时失败！"))
-        }
+        self.send(PlayerCommand::Previous, "切换上一首歌曲时失败！")
+            .await?;
+        let result = PreviousResponse {
+            success: true,
+            message: "成功切换到上一首歌曲".into(),
+        };
+        Ok(Response::new(result))
     }
 
-    async fn stop(&self, _request: Request<StopRequest>) -> Result<Response<StopResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Stop).await).is_ok() {
+    async fn stop(&self, request: Request<StopRequest>) -> Result<Response<StopResponse>, Status> {
+        // 关闭服务时由播放器淡出并停止播放
+        if request.into_inner().quit {
+            tracing::info!("Quit requested by client");
+            self.shutdown.send_replace(true);
             let result = StopResponse {
                 success: true,
-                message: "停止播放".into(),
+                message: "正在关闭播放器".into(),
             };
             return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("停止播放时失败！"))
         }
+        self.send(PlayerCommand::Stop, "停止播放时失败！").await?;
+        let result = StopResponse {
+            success: true,
+            message: "停止播放".into(),
+        };
+        Ok(Response::new(result))
     }
     async fn resume(
         &self,
         _request: Request<ResumeRequest>,
     ) -> Result<Response<ResumeResponse>, Status> {
-        if (self.command_sender.send(PlayerCommand::Resume).await).is_ok() {
-            let result = ResumeResponse {
-                success: true,
-                message: "恢复播放".into(),
-            };
-            return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("恢复播放时失败！"))
-        }
+        self.send(PlayerCommand::Resume, "恢复播放时失败！").await?;
+        let result = ResumeResponse {
+            success: true,
+            message: "恢复播放".into(),
+        };
+        Ok(Response::new(result))
     }
     async fn set_model(
         &self,
         request: Request<SetModelRequest>,
     ) -> Result<Response<SetModelResponse>, Status> {
         let input = request.into_inner();
-        let model = PlayMode::from_string(input.model.as_str()).unwrap();
-        if (self
-            .command_sender
-            .send(PlayerCommand::SetModel(input))
-            .await)
-            .is_ok()
-        {
-            let info = format!("{} 模式设置成功!", model.get_string());
-            let result = SetModelResponse {
-                success: true,
-                message: info,
-            };
-            return Ok(Response::new(result));
-        } else {
-            Err(Status::internal("设置播放模式失败"))
-        }
+        let model = PlayMode::from_string(input.model.as_str())
+            .ok_or_else(|| Status::invalid_argument(format!("未知的播放模式: {}", input.model)))?;
+        self.send(PlayerCommand::SetModel(input), "设置播放模式失败")
+            .await?;
+        let info = format!("{} 模式设置成功!", model.get_string());
+        let result = SetModelResponse {
+            success: true,
+            message: info,
+        };
+        Ok(Response::new(result))
     }
     async fn add_playlist(
         &self,
-        _request: Request<AddPlaylistRequest>,
+        request: Request<AddPlaylistRequest>,
     ) -> Result<Response<AddPlaylistResponse>, Status> {
-        todo!()
+        let input = request.into_inner();
+        let bvid = input.bvid.trim();
+        if bvid.is_empty() {
+            return Err(Status::invalid_argument("bvid 不能为空"));
+        }
+        // 先获取歌曲信息再发送命令，等待 B 站返回期间不阻塞播放控制
+        let mut music = MusicInfo::from(fetch_video_data(&self.client, bvid).await?);
+        // 用户指定了歌名时优先使用，视频标题通常比较长
+        let song_name = input.song_name.trim();
+        if !song_name.is_empty() {
+            music.title = song_name.to_string();
+        }
+        let (index, music) = self
+            .call(
+                |sender| PlayerCommand::AddPlaylist { music, sender },
+                "添加歌曲到播放列表失败！",
+            )
+            .await?;
+        let result = AddPlaylistResponse {
+            success: true,
+            message: format!("已添加到播放列表第{}首: {}", index + 1, music),
+            title: music.title,
+            owner: music.owner,
+            duration: music.duration,
+            index: index as u32 + 1,
+        };
+        Ok(Response::new(result))
     }
     async fn deleted(
         &self,
-        _request: Request<DeletedRequest>,
+        request: Request<DeletedRequest>,
     ) -> Result<Response<DeletedResponse>, Status> {
-        todo!()
+        let input = request.into_inner();
+        let removed = self
+            .call(
+                |sender| PlayerCommand::Delete {
+                    request: input,
+                    sender,
+                },
+                "从播放列表中删除歌曲失败！",
+            )
+            .await?;
+        let titles: Vec<String> = removed
+            .iter()
+            .map(|music| format!("《{}》", music.title))
+            .collect();
+        let result = DeletedResponse {
+            success: true,
+            message: format!("已删除 {} 首歌曲: {}", removed.len(), titles.join(" ")),
+            removed: removed.len() as u32,
+        };
+        Ok(Response::new(result))
     }
     async fn get_state(
         &self,
         _request: Request<GetStateRequest>,
     ) -> Result<Response<GetStateResponse>, Status> {
-        // 创建一个 oneshot channel
-        let (sender, receiver) = oneshot::channel::<PlayerState>();
-        // 发送消息到播放器
-        if (self
-            .command_sender
-            .send(PlayerCommand::GetState(sender))
-            .await)
-            .is_ok()
-        {
-            // 等待响应
-            match receiver.await {
-                Ok(state) => {
-                    let result = GetStateResponse {
-                        success: true,
-                        message: state.to_string(),
-                    };
-                    return Ok(Response::new(result));
-                }
-                Err(_) => Err(Status::internal("获取播放器状态失败")),
-            }
-        } else {
-            Err(Status::internal("获取播放器状态失败"))
-        }
+        let state: PlayerState = self
+            .ask(PlayerCommand::GetState, "获取播放器状态失败")
+            .await?;
+        Ok(Response::new(GetStateResponse::from(state)))
     }
     async fn show_music_page_info(
         &self,
         request: Request<ShowMusicPageInfoRequest>,
     ) -> Result<Response<ShowMusicPageInfoResponse>, Status> {
         let page = request.into_inner().page;
-        // 创建一个 oneshot channel
-        let (sender, receiver) = oneshot::channel::<ShowMusicPageInfoResponse>();
-        // 发送消息到播放器
-        if (self
-            .command_sender
-            .send(PlayerCommand::ShowMusicPageInfo { page, sender })
-            .await)
-            .is_ok()
-        {
-            // 等待响应
-            match receiver.await {
-                Ok(state) => {
-                    return Ok(Response::new(state));
-                }
-                Err(_) => Err(Status::internal("获取当前页的音乐信息失败!")),
-            }
-        } else {
-            Err(Status::internal("获取播放器状态失败"))
-        }
+        let page_info = self
+            .ask(
+                |sender| PlayerCommand::ShowMusicPageInfo { page, sender },
+                "获取当前页的音乐信息失败!",
+            )
+            .await?;
+        Ok(Response::new(page_info))
     }
     async fn set_volume(
         &self,
         request: Request<SetVolumeRequest>,
     ) -> Result<Response<SetVolumeResponse>, Status> {
         let input = request.into_inner();
-        if (self
-            .command_sender
-            .send(PlayerCommand::SetVolume(input))
-            .await)
-            .is_ok()
-        {
-            let result = SetVolumeResponse {
-                success: true,
-                message: "音量设置成功".into(),
-            };
-            return Ok(Response::new(result));
+        self.send(PlayerCommand::SetVolume(input), "设置音量时失败！")
+            .await?;
+        let result = SetVolumeResponse {
+            success: true,
+            message: "音量设置成功".into(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn find(&self, request: Request<FindRequest>) -> Result<Response<FindResponse>, Status> {
+        let input = request.into_inner();
+        // 空字符串表示不按该字段查找
+        let field = |value: String| Some(value).filter(|value| !value.trim().is_empty());
+        let query = MusicQuery {
+            bvid: field(input.bvid),
+            cid: field(input.cid),
+            title: field(input.title),
+            owner: field(input.owner),
+        };
+        if query.is_empty() {
+            return Err(Status::invalid_argument("至少需要指定一个查找条件"));
+        }
+        let mut found = self
+            .ask(
+                |sender| PlayerCommand::Find { query, sender },
+                "查找歌曲失败！",
+            )
+            .await?;
+        if input.limit > 0 {
+            found.truncate(input.limit as usize);
+        }
+        let result = FindResponse {
+            success: true,
+            message: format!("共找到 {} 首歌曲", found.len()),
+            results: found
+                .into_iter()
+                .map(|(index, music)| FoundMusic {
+                    index: index as u32 + 1,
+                    music: Some(music.into()),
+                })
+                .collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn seek(&self, request: Request<SeekRequest>) -> Result<Response<SeekResponse>, Status> {
+        let input = request.into_inner();
+        // 把请求参数转换为跳转目标
+        let target = match input.mode() {
+            SeekMode::Absolute => {
+                if input.position < 0 {
+                    return Err(Status::invalid_argument("跳转位置不能为负数"));
+                }
+                SeekTarget::Absolute(gstreamer::ClockTime::from_mseconds(input.position as u64))
+            }
+            SeekMode::Relative => SeekTarget::Relative(input.position),
+            SeekMode::Percent => SeekTarget::Percent(input.position as f64),
+        };
+        let (position, duration) = self
+            .call(
+                |sender| PlayerCommand::Seek { target, sender },
+                "跳转播放位置失败！",
+            )
+            .await?;
+        let result = SeekResponse {
+            success: true,
+            message: format!(
+                "已跳转到 {}/{}",
+                format_clock_time(Some(position)),
+                format_clock_time(Some(duration))
+            ),
+            position_ms: position.mseconds(),
+            duration_ms: duration.mseconds(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn subscribe(
+        &self,
+        _request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let events = self
+            .ask(PlayerCommand::Subscribe, "订阅播放器事件失败！")
+            .await?;
+        let stream = stream::unfold(events, |mut events| async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => return Some((Ok(event.into()), events)),
+                    // 订阅者处理太慢时跳过丢失的事件，继续推送
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Subscriber lagged, skipped {} events", skipped);
+                    }
+                    // 播放器已退出
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        // 服务关闭时结束推送，否则订阅的连接会一直阻止服务退出
+        let mut shutdown = self.shutdown.subscribe();
+        let stream = stream.take_until(async move {
+            let _ = shutdown.wait_for(|&shutdown| shutdown).await;
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+    async fn queue_next(
+        &self,
+        request: Request<QueueNextRequest>,
+    ) -> Result<Response<QueueNextResponse>, Status> {
+        let input = request.into_inner();
+        let (index, music) = self.queue(input.bvid, QueuePosition::Next).await?;
+        let result = QueueNextResponse {
+            success: true,
+            message: format!("下一首播放: 《{}》", music.title),
+            music: Some(music.into()),
+            position: index as u32 + 1,
+        };
+        Ok(Response::new(result))
+    }
+    async fn queue_last(
+        &self,
+        request: Request<QueueLastRequest>,
+    ) -> Result<Response<QueueLastResponse>, Status> {
+        let input = request.into_inner();
+        let (index, music) = self.queue(input.bvid, QueuePosition::Last).await?;
+        let result = QueueLastResponse {
+            success: true,
+            message: format!("已加入待播队列第 {} 首: 《{}》", index + 1, music.title),
+            music: Some(music.into()),
+            position: index as u32 + 1,
+        };
+        Ok(Response::new(result))
+    }
+    async fn clear_queue(
+        &self,
+        _request: Request<ClearQueueRequest>,
+    ) -> Result<Response<ClearQueueResponse>, Status> {
+        let cleared = self
+            .ask(PlayerCommand::ClearQueue, "清空待播队列失败！")
+            .await?;
+        let result = ClearQueueResponse {
+            success: true,
+            message: format!("已清空待播队列中的 {} 首歌曲", cleared),
+            cleared: cleared as u32,
+        };
+        Ok(Response::new(result))
+    }
+    async fn list_queue(
+        &self,
+        _request: Request<ListQueueRequest>,
+    ) -> Result<Response<ListQueueResponse>, Status> {
+        let musics = self
+            .ask(PlayerCommand::ListQueue, "获取待播队列失败！")
+            .await?;
+        let result = ListQueueResponse {
+            success: true,
+            message: format!("待播队列中共有 {} 首歌曲", musics.len()),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn create_playlist(
+        &self,
+        request: Request<CreatePlaylistRequest>,
+    ) -> Result<Response<CreatePlaylistResponse>, Status> {
+        let name = playlist_name(request.into_inner().name)?;
+        let message = format!("已创建歌单: {}", name);
+        self.call(
+            |sender| PlayerCommand::CreatePlaylist { name, sender },
+            "创建歌单失败！",
+        )
+        .await?;
+        let result = CreatePlaylistResponse {
+            success: true,
+            message,
+        };
+        Ok(Response::new(result))
+    }
+    async fn rename_playlist(
+        &self,
+        request: Request<RenamePlaylistRequest>,
+    ) -> Result<Response<RenamePlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let name = playlist_name(input.name)?;
+        let new_name = playlist_name(input.new_name)?;
+        let message = format!("歌单 {} 已重命名为 {}", name, new_name);
+        self.call(
+            |sender| PlayerCommand::RenamePlaylist {
+                name,
+                new_name,
+                sender,
+            },
+            "重命名歌单失败！",
+        )
+        .await?;
+        let result = RenamePlaylistResponse {
+            success: true,
+            message,
+        };
+        Ok(Response::new(result))
+    }
+    async fn delete_playlist(
+        &self,
+        request: Request<DeletePlaylistRequest>,
+    ) -> Result<Response<DeletePlaylistResponse>, Status> {
+        let name = playlist_name(request.into_inner().name)?;
+        let message = format!("已删除歌单: {}", name);
+        self.call(
+            |sender| PlayerCommand::DeletePlaylist { name, sender },
+            "删除歌单失败！",
+        )
+        .await?;
+        let result = DeletePlaylistResponse {
+            success: true,
+            message,
+        };
+        Ok(Response::new(result))
+    }
+    async fn add_to_playlist(
+        &self,
+        request: Request<AddToPlaylistRequest>,
+    ) -> Result<Response<AddToPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let name = playlist_name(input.name)?;
+        let bvid = input.bvid.trim();
+        if bvid.is_empty() {
+            return Err(Status::invalid_argument("bvid 不能为空"));
+        }
+        // 音乐库中没有的歌曲先获取歌曲信息，等待 B 站返回期间不阻塞播放控制
+        let music = match self.library.find_by_bvid(bvid).await? {
+            Some(record) => MusicInfo::from(record),
+            None => MusicInfo::from(fetch_video_data(&self.client, bvid).await?),
+        };
+        let message = format!("已添加到歌单 {}", name);
+        let music = self
+            .call(
+                |sender| PlayerCommand::AddToPlaylist {
+                    name,
+                    music,
+                    sender,
+                },
+                "添加到歌单失败！",
+            )
+            .await?;
+        let result = AddToPlaylistResponse {
+            success: true,
+            message: format!("{}: 《{}》", message, music.title),
+            music: Some(music.into()),
+        };
+        Ok(Response::new(result))
+    }
+    async fn remove_from_playlist(
+        &self,
+        request: Request<RemoveFromPlaylistRequest>,
+    ) -> Result<Response<RemoveFromPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let name = playlist_name(input.name)?;
+        let message = format!("已从歌单 {} 中移除 {}", name, input.bvid);
+        self.call(
+            |sender| PlayerCommand::RemoveFromPlaylist {
+                name,
+                bvid: input.bvid,
+                sender,
+            },
+            "从歌单中移除歌曲失败！",
+        )
+        .await?;
+        let result = RemoveFromPlaylistResponse {
+            success: true,
+            message,
+        };
+        Ok(Response::new(result))
+    }
+    async fn switch_playlist(
+        &self,
+        request: Request<SwitchPlaylistRequest>,
+    ) -> Result<Response<SwitchPlaylistResponse>, Status> {
+        let name = request.into_inner().name.trim().to_string();
+        let display = if name.is_empty() {
+            "音乐库".to_string()
         } else {
-            Err(Status::internal("设置音量时失败！"))
+            name.clone()
+        };
+        let name = Some(name).filter(|name| !name.is_empty());
+        let (length, index, music) = self
+            .call(
+                |sender| PlayerCommand::SwitchPlaylist { name, sender },
+                "切换歌单失败！",
+            )
+            .await?;
+        let result = SwitchPlaylistResponse {
+            success: true,
+            message: format!("已切换到 {}，共 {} 首歌曲", display, length),
+            length: length as u32,
+            index: index.map(|index| index as u32 + 1),
+            music: music.map(Into::into),
+        };
+        Ok(Response::new(result))
+    }
+    async fn list_playlists(
+        &self,
+        _request: Request<ListPlaylistsRequest>,
+    ) -> Result<Response<ListPlaylistsResponse>, Status> {
+        let playlists = self
+            .call(PlayerCommand::ListPlaylists, "获取歌单列表失败！")
+            .await?;
+        let result = ListPlaylistsResponse {
+            success: true,
+            message: format!("共有 {} 个歌单", playlists.len() - 1),
+            playlists,
+        };
+        Ok(Response::new(result))
+    }
+    async fn move_track(
+        &self,
+        request: Request<MoveTrackRequest>,
+    ) -> Result<Response<MoveTrackResponse>, Status> {
+        let input = request.into_inner();
+        let op = Reorder::Move {
+            from: track_index(input.from)?,
+            to: track_index(input.to)?,
+        };
+        let index = self.reorder(op).await?;
+        let result = MoveTrackResponse {
+            success: true,
+            message: format!("已将第 {} 首移动到第 {} 首", input.from, input.to),
+            index,
+        };
+        Ok(Response::new(result))
+    }
+    async fn swap_tracks(
+        &self,
+        request: Request<SwapTracksRequest>,
+    ) -> Result<Response<SwapTracksResponse>, Status> {
+        let input = request.into_inner();
+        let op = Reorder::Swap(track_index(input.first)?, track_index(input.second)?);
+        let index = self.reorder(op).await?;
+        let result = SwapTracksResponse {
+            success: true,
+            message: format!("已交换第 {} 首和第 {} 首", input.first, input.second),
+            index,
+        };
+        Ok(Response::new(result))
+    }
+    async fn sort_playlist(
+        &self,
+        request: Request<SortPlaylistRequest>,
+    ) -> Result<Response<SortPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let key = bili_player::pb::SortKey::try_from(input.key)
+            .map_err(|_| Status::invalid_argument(format!("未知的排序依据: {}", input.key)))?;
+        let op = Reorder::Sort {
+            key: SortKey::from(key),
+            descending: input.descending,
+        };
+        let index = self.reorder(op).await?;
+        let result = SortPlaylistResponse {
+            success: true,
+            message: format!(
+                "已按{}{}排序",
+                SortKey::from(key).get_string(),
+                if input.descending { "降序" } else { "升序" }
+            ),
+            index,
+        };
+        Ok(Response::new(result))
+    }
+    async fn update_credentials(
+        &self,
+        request: Request<UpdateCredentialsRequest>,
+    ) -> Result<Response<UpdateCredentialsResponse>, Status> {
+        let input = request.into_inner();
+        let credentials = Credentials {
+            sessdata: input.sessdata,
+            bili_jct: input.bili_jct,
+            buvid3: input.buvid3,
+        };
+        if credentials == Credentials::default() {
+            return Err(Status::invalid_argument("没有需要更新的登录凭据"));
         }
+        // 凭据与播放器共用，更新后之后的请求和播放都会使用新的凭据
+        // 写入 Cookie 文件和请求 nav 接口都不经过播放器，不阻塞播放控制
+        self.credentials.update(credentials)?;
+        let status = self.credentials.login_status(&self.client).await?;
+        if status.logged_in {
+            tracing::info!("Logged in as {}", status.uname);
+        }
+        let message = if status.logged_in {
+            format!("登录成功：{}", status.uname)
+        } else {
+            "已保存登录凭据，但凭据无效或已过期".to_string()
+        };
+        let result = UpdateCredentialsResponse {
+            success: true,
+            message,
+            logged_in: status.logged_in,
+            uname: status.uname,
+        };
+        Ok(Response::new(result))
     }
-    async fn seek(&self, _request: Request<SeekRequest>) -> Result<Response<SeekResponse>, Status> {
-        todo!()
+    async fn login_status(
+        &self,
+        _request: Request<LoginStatusRequest>,
+    ) -> Result<Response<LoginStatusResponse>, Status> {
+        // 请求 nav 接口不经过播放器，不阻塞播放控制
+        let status = self.credentials.login_status(&self.client).await?;
+        let message = if status.logged_in {
+            format!(
+                "已登录：{}（UID {}）{}",
+                status.uname,
+                status.mid,
+                if status.vip { "，大会员" } else { "" }
+            )
+        } else {
+            "未登录或登录已失效".to_string()
+        };
+        let result = LoginStatusResponse {
+            success: true,
+            message,
+            logged_in: status.logged_in,
+            uname: status.uname,
+            mid: status.mid,
+            vip: status.vip,
+        };
+        Ok(Response::new(result))
+    }
+}
+/// 把从 1 开始的歌曲序号转换为从 0 开始的索引
+fn track_index(index: u32) -> Result<usize, Status> {
+    if index == 0 {
+        return Err(Status::invalid_argument("歌曲序号从 1 开始"));
+    }
+    Ok(index as usize - 1)
+}
+/// 检查歌单名称不为空
+fn playlist_name(name: String) -> Result<String, Status> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Status::invalid_argument("歌单名称不能为空"));
+    }
+    Ok(name.to_string())
+}
+/// 等待关闭服务的信号：Ctrl-C、SIGTERM 或客户端请求退出
+async fn shutdown_signal(shutdown: Arc<watch::Sender<bool>>) {
+    let mut quit = shutdown.subscribe();
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Received SIGINT, shutting down"),
+        _ = terminate => tracing::info!("Received SIGTERM, shutting down"),
+        _ = quit.wait_for(|&quit| quit) => tracing::info!("Shutting down on client request"),
+    }
+    // 通知事件订阅流结束
+    shutdown.send_replace(true);
+}
+/// 绑定 Unix socket，socket 文件只有当前用户可以读写
+///
+/// socket 绑定在只有当前用户可以访问的目录下，绑定之后再修改文件权限之前也不会被其他用户连上。
+#[cfg(unix)]
+fn bind_unix_socket(path: &Path) -> anyhow::Result<UnixListenerStream> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    if let Some(dir) = path.parent() {
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(dir)?;
     }
+    ServerConfig::check_socket_dir(path)?;
+    if path.exists() {
+        // 还能连上说明已经有播放器在运行，否则是上次异常退出留下的文件
+        if std::os::unix::net::UnixStream::connect(path).is_ok() {
+            anyhow::bail!("播放器已经在 {} 上运行", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = tokio::net::UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    tracing::info!("UserServiceServer listening on {}", path.display());
+    Ok(UnixListenerStream::new(listener))
+}
+/// 把 musics.txt 格式的文件导入音乐库
+async fn import_library(repository: &MusicRepository, file: &Path) -> anyhow::Result<()> {
+    let report = import_music_file(repository, file).await?;
+    for skipped in &report.skipped {
+        tracing::warn!("Skipped {}", skipped);
+    }
+    tracing::info!(
+        "Imported {} musics from {}, skipped {} lines",
+        report.imported,
+        file.display(),
+        report.skipped.len()
+    );
+    Ok(())
 }
+/// 等待播放器保存状态并停止的最长时间
+const PLAYER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // 解析命令行参数
+    let args = ServerArgs::parse();
+    // 读取配置文件，命令行参数优先
+    let mut config = Config::load(args.config.as_deref())?.server;
+    args.apply(&mut config);
+    let play_mode = PlayMode::from_string(&config.play_mode)
+        .ok_or_else(|| anyhow::anyhow!("未知的播放模式: {}", config.play_mode))?;
+    let audio_preference = AudioPreference::from_string(&config.audio_quality)
+        .ok_or_else(|| anyhow::anyhow!("未知的音质偏好: {}", config.audio_quality))?;
+    std::fs::create_dir_all(&config.cache_dir)?;
     // 初始化日志
-    init_logger("info").await?;
-    let file = "musics.txt";
-    let (mut player, command_sender) = AudioPlayer::new(file).await?;
+    let log_dir = config.log.file.then(|| config.cache_dir.join("logs"));
+    init_logger_with_file(&config.log.level, log_dir.as_deref()).await?;
+    // 连接音乐库，启动时执行数据库迁移
+    let pool = library::connect(&config.database_url).await?;
+    let repository = MusicRepository::new(pool);
+    // 只导入文件，不启动服务
+    if let Some(file) = args.import {
+        import_library(&repository, &file).await?;
+        return Ok(());
+    }
+    // 第一次启动时从歌曲列表文件初始化音乐库
+    if let Some(file) = &config.library
+        && repository.count().await? == 0
+    {
+        import_library(&repository, file).await?;
+    }
+    // 读取登录凭据，没有凭据时只能获取低码率的音频
+    let credentials =
+        CredentialStore::new(config.load_credentials()?).persist_to(config.login_cookie_file());
+    if !credentials.credentials().has_session() {
+        tracing::info!("No login credentials configured, playing as guest");
+    }
+    let client = credentials.client()?;
+    let (mut player, command_sender) =
+        AudioPlayer::new(repository.clone(), credentials.clone()).await?;
+    player
+        .apply_defaults(config.volume, play_mode, audio_preference)
+        .await?;
+    player.set_cdn_hosts(config.cdn_hosts.clone()).await;
+    // 恢复上次的播放状态，恢复失败时从头开始
+    if let Err(e) = player.restore_session(args.resume).await {
+        tracing::warn!("Failed to restore session: {}", e);
+    }
     // 启动播放服务
-    tokio::task::spawn({
+    let player_task = tokio::task::spawn({
         async move {
             if let Err(e) = player.run().await {
                 tracing::error!("Player error: {}", e);
@@ -252,15 +1030,57 @@ async fn main() -> anyhow::Result<()> {
     });
     // 如果要启动就播放，需要发送 PlayerCommand::Play 信号
     // command_sender.send(PlayerCommand::Play).await?;
-    // grpc 服务地址
-    let addr = "[::1]:50052".parse().unwrap();
     // 创建grpc服务
-    let svc = PlayerServer::new(command_sender);
-    tracing::info!("UserServiceServer listening on {addr}");
-    // 启动服务
-    Server::builder()
-        .add_service(PlayerServiceServer::new(svc))
-        .serve(addr)
-        .await?;
+    let (shutdown, _) = watch::channel(false);
+    let shutdown = Arc::new(shutdown);
+    let svc = PlayerServer::new(
+        command_sender,
+        shutdown.clone(),
+        client,
+        repository,
+        credentials,
+    );
+    // 配置了访问令牌时每个请求都需要认证，拦截器根据记录的方法路径检查权限
+    let auth = Authenticator::new(config.tokens.clone());
+    let router = Server::builder()
+        .layer(MapRequestLayer::new(record_grpc_path))
+        .add_service(PlayerServiceServer::with_interceptor(svc, auth.clone()));
+    // 启动服务，收到关闭信号后等待正在处理的请求完成再退出
+    if config.unix_socket {
+        #[cfg(unix)]
+        {
+            let path = config.socket_path();
+            let incoming = bind_unix_socket(&path)?;
+            let result = router
+                .serve_with_incoming_shutdown(incoming, shutdown_signal(shutdown))
+                .await;
+            let _ = std::fs::remove_file(&path);
+            result?;
+        }
+        #[cfg(not(unix))]
+        anyhow::bail!("当前平台不支持 Unix socket");
+    } else {
+        let addr: SocketAddr = config
+            .listen
+            .parse()
+            .map_err(|e| anyhow::anyhow!("无效的监听地址 {}: {}", config.listen, e))?;
+        if !addr.ip().is_loopback() && !auth.is_enabled() {
+            tracing::warn!(
+                "Listening on {addr} without access tokens, anyone can control the player"
+            );
+        }
+        tracing::info!("UserServiceServer listening on {addr}");
+        router
+            .serve_with_shutdown(addr, shutdown_signal(shutdown))
+            .await?;
+    }
+    // 服务退出后命令通道随之关闭，播放器保存状态并停止播放
+    if tokio::time::timeout(PLAYER_SHUTDOWN_TIMEOUT, player_task)
+        .await
+        .is_err()
+    {
+        tracing::warn!("Player did not stop within {:?}", PLAYER_SHUTDOWN_TIMEOUT);
+    }
+    tracing::info!("Server stopped");
     Ok(())
 }
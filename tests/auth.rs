@@ -0,0 +1,56 @@
+use bili_player::{
+    auth::{Authenticator, GrpcPath, Scope, TokenInterceptor},
+    config::Config,
+};
+use tonic::{Code, Request, service::Interceptor};
+
+/// 构造调用指定方法的请求，token 经过客户端拦截器附带
+fn request(method: &str, token: Option<&str>) -> Request<()> {
+    let mut request = Request::new(());
+    request
+        .extensions_mut()
+        .insert(GrpcPath(format!("/player.PlayerService/{}", method)));
+    TokenInterceptor::new(token).unwrap().call(request).unwrap()
+}
+
+#[test]
+fn tokens_are_checked_by_scope() {
+    let config = Config::from_toml(
+        r#"
+        [[server.tokens]]
+        token = "admin-secret"
+
+        [[server.tokens]]
+        token = "viewer-secret"
+        scope = "read"
+        name = "phone"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.server.tokens[0].scope, Scope::Full);
+    let mut auth = Authenticator::new(config.server.tokens);
+
+    let code = |result: Result<Request<()>, tonic::Status>| result.err().map(|s| s.code());
+    assert_eq!(
+        code(auth.call(request("GetState", None))),
+        Some(Code::Unauthenticated)
+    );
+    assert_eq!(
+        code(auth.call(request("GetState", Some("wrong")))),
+        Some(Code::Unauthenticated)
+    );
+    assert_eq!(
+        code(auth.call(request("GetState", Some("viewer-secret")))),
+        None
+    );
+    assert_eq!(
+        code(auth.call(request("Next", Some("viewer-secret")))),
+        Some(Code::PermissionDenied)
+    );
+    assert_eq!(code(auth.call(request("Next", Some("admin-secret")))), None);
+
+    // 没有配置令牌时不需要认证
+    let mut open = Authenticator::new(Vec::new());
+    assert!(!open.is_enabled());
+    assert_eq!(code(open.call(request("Next", None))), None);
+}
@@ -0,0 +1,69 @@
+use bili_player::fetch::{
+    cdn::{CdnHosts, replace_host, url_host},
+    stream::AudioStream,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// 启动模拟的 CDN，所有请求都返回指定的状态行，返回 host:port
+async fn mock_cdn(status: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 4096];
+            let _ = stream.read(&mut buffer).await;
+            let response =
+                format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+    addr.to_string()
+}
+
+#[tokio::test]
+async fn fall_back_to_backup_and_rewritten_hosts() {
+    let broken = mock_cdn("404 Not Found").await;
+    let working = mock_cdn("206 Partial Content").await;
+    let stream = AudioStream {
+        id: 30280,
+        base_url: format!("http://{broken}/upgcxcode/30280.m4s?deadline=1"),
+        backup_urls: vec![format!("http://{broken}/backup/30280.m4s")],
+        bandwidth: 192000,
+        codecs: "mp4a.40.2".to_string(),
+    };
+    let cdn = CdnHosts::new(vec![working.clone()]);
+    let candidates = cdn.candidates(&stream);
+    assert_eq!(candidates.len(), 3);
+    assert_eq!(
+        candidates[2],
+        format!("http://{working}/upgcxcode/30280.m4s?deadline=1")
+    );
+
+    let client = reqwest::Client::builder().no_proxy().build().unwrap();
+    let url = cdn.find_working_url(&client, &stream).await.unwrap();
+    assert_eq!(url, candidates[2]);
+    assert_eq!(cdn.working_host(), Some(working.clone()));
+    // 之后优先尝试上次可用的域名
+    assert_eq!(url_host(&cdn.candidates(&stream)[0]), Some(working));
+
+    assert!(
+        CdnHosts::default()
+            .find_working_url(
+                &client,
+                &AudioStream {
+                    backup_urls: Vec::new(),
+                    ..stream
+                }
+            )
+            .await
+            .is_none()
+    );
+    assert_eq!(
+        replace_host("https://a.bilivideo.com:4483/x.m4s", "b.bilivideo.com").as_deref(),
+        Some("https://b.bilivideo.com/x.m4s")
+    );
+}
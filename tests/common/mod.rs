@@ -0,0 +1,13 @@
+use bili_player::player::model::MusicInfo;
+
+/// 测试用的歌曲，bvid、cid 和标题由序号生成
+pub fn music(i: usize) -> MusicInfo {
+    MusicInfo {
+        bvid: format!("BV{:010}", i),
+        cid: i.to_string(),
+        title: format!("歌曲{}", i),
+        artist: None,
+        owner: "up".into(),
+        duration: 60,
+    }
+}
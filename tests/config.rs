@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use bili_player::config::{
+    APP_DIR, COOKIE_FILE, Config, DEFAULT_LISTEN, SOCKET_FILE, ServerConfig,
+};
+
+#[test]
+fn partial_config_uses_defaults() {
+    let config = Config::from_toml(
+        r#"
+        [server]
+        listen = "127.0.0.1:6000"
+        volume = 40
+
+        [server.log]
+        file = true
+        "#,
+    )
+    .unwrap();
+    assert_eq!(config.server.listen, "127.0.0.1:6000");
+    assert_eq!(config.server.volume, 40);
+    assert_eq!(config.server.play_mode, "normal");
+    assert_eq!(config.server.audio_quality, "192k");
+    assert_eq!(config.server.log.level, "info");
+    assert!(config.server.log.file);
+    // 没有单独配置时 bpc 连接 server.listen
+    assert_eq!(config.server_url(), "http://127.0.0.1:6000");
+
+    let config = Config::from_toml("[client]\nserver = \"http://remote:50052\"").unwrap();
+    assert_eq!(config.server.listen, DEFAULT_LISTEN);
+    assert_eq!(config.server_url(), "http://remote:50052");
+    assert!(
+        config
+            .server
+            .socket_path()
+            .ends_with(format!("{}/{}", APP_DIR, SOCKET_FILE))
+    );
+    assert!(Config::from_toml("[server]\nvolume = \"loud\"").is_err());
+}
+
+#[test]
+fn login_does_not_overwrite_configured_cookie_file() {
+    let mut config = Config::from_toml("[server]\ncookie_file = \"/tmp/exported.txt\"").unwrap();
+    assert_eq!(
+        config.server.cookie_file_path(),
+        PathBuf::from("/tmp/exported.txt")
+    );
+    assert_eq!(
+        config.server.login_cookie_file(),
+        ServerConfig::default_cookie_file()
+    );
+    assert!(
+        config
+            .server
+            .login_cookie_file()
+            .ends_with(format!("{}/{}", APP_DIR, COOKIE_FILE))
+    );
+
+    config.server.overwrite_cookie_file = true;
+    assert_eq!(
+        config.server.login_cookie_file(),
+        PathBuf::from("/tmp/exported.txt")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn socket_dir_must_be_private() {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = std::env::temp_dir().join(format!("bili_player_socket_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let socket = dir.join(SOCKET_FILE);
+
+    std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+    assert!(ServerConfig::check_socket_dir(&socket).is_err());
+    std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).unwrap();
+    ServerConfig::check_socket_dir(&socket).unwrap();
+    // 临时目录本身所有用户都可以写，不能直接放 socket
+    assert!(ServerConfig::check_socket_dir(&std::env::temp_dir().join(SOCKET_FILE)).is_err());
+    let _ = std::fs::remove_dir(&dir);
+}
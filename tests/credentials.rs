@@ -0,0 +1,86 @@
+use bili_player::fetch::credentials::{CredentialStore, Credentials};
+
+const BILIBILI: &str = "https://www.bilibili.com";
+
+#[test]
+fn parse_cookie_files_and_build_header() {
+    // Netscape cookies.txt，其他站点的同名 Cookie 会被忽略
+    let netscape = "# Netscape HTTP Cookie File\n\
+        #HttpOnly_.bilibili.com\tTRUE\t/\tTRUE\t1790000000\tSESSDATA\tabc%2C123\n\
+        .bilibili.com\tTRUE\t/\tFALSE\t1790000000\tbili_jct\tcsrf\n\
+        .example.com\tTRUE\t/\tFALSE\t1790000000\tbuvid3\tother\n";
+    let credentials = Credentials::parse_cookies(netscape);
+    assert_eq!(credentials.sessdata.as_deref(), Some("abc%2C123"));
+    assert_eq!(credentials.bili_jct.as_deref(), Some("csrf"));
+    assert_eq!(credentials.buvid3, None);
+
+    let header = Credentials::parse_cookies("Cookie: buvid3=device; SESSDATA=new; foo=bar");
+    assert_eq!(header.buvid3.as_deref(), Some("device"));
+    // 后合并的凭据优先
+    let merged = credentials.merge(header);
+    assert_eq!(merged.sessdata.as_deref(), Some("new"));
+    assert_eq!(merged.bili_jct.as_deref(), Some("csrf"));
+    assert!(merged.has_session());
+
+    assert_eq!(CredentialStore::default().cookie_header(BILIBILI), None);
+    let store = CredentialStore::new(merged);
+    let cookie = store.cookie_header(BILIBILI).unwrap();
+    for pair in ["SESSDATA=new", "bili_jct=csrf", "buvid3=device"] {
+        assert!(cookie.contains(pair), "{cookie}");
+    }
+    store
+        .update(Credentials {
+            sessdata: Some("refreshed".to_string()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert!(
+        store
+            .cookie_header(BILIBILI)
+            .unwrap()
+            .contains("SESSDATA=refreshed")
+    );
+    assert_eq!(store.credentials().bili_jct.as_deref(), Some("csrf"));
+}
+
+#[test]
+fn cookie_is_only_sent_to_bilibili_hosts() {
+    let store = CredentialStore::new(Credentials::parse_cookies("SESSDATA=secret"));
+    for url in [
+        "https://api.bilibili.com/x/web-interface/nav",
+        "https://bilibili.com/",
+    ] {
+        assert_eq!(store.cookie_header(url).as_deref(), Some("SESSDATA=secret"));
+    }
+    // 音频地址都在 CDN 上，不需要也不应该携带登录 Cookie
+    for url in [
+        "https://upos-sz-mirrorcos.bilivideo.com/upgcxcode/1.m4s?deadline=1",
+        "https://upos-hz-mirrorakam.akamaized.net/upgcxcode/1.m4s",
+        "https://xy1x2x3x4xy.mcdn.bilivideo.cn:4483/upgcxcode/1.m4s",
+        "https://evilbilibili.com/",
+        "not a url",
+    ] {
+        assert_eq!(store.cookie_header(url), None, "{url}");
+    }
+}
+
+#[test]
+fn saved_cookie_file_is_private() {
+    let path = std::env::temp_dir().join(format!("bili_player_cookies_{}.txt", std::process::id()));
+    std::fs::write(&path, "old").unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+    }
+    let credentials = Credentials::parse_cookies("SESSDATA=secret");
+    credentials.save_cookie_file(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "SESSDATA=secret\n");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    let _ = std::fs::remove_file(&path);
+}
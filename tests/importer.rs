@@ -0,0 +1,25 @@
+use bili_player::library::{self, importer::import_music_file, repository::MusicRepository};
+
+#[tokio::test]
+async fn importing_the_same_file_twice_is_idempotent() {
+    let path = std::env::temp_dir().join(format!("bili_player_import_{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "《青花瓷》-04:02-BV1r7411p7R4-321818216-周杰伦-音乐无限\n\
+         \n\
+         《长大成人》-03:37-BV1rU4y1Y71M-794672205-范茹-OYMusicChannel\n\
+         《晴天》-04:29-BV1oZqq-1-周杰伦-音乐\n",
+    )
+    .unwrap();
+    let pool = library::connect("sqlite::memory:").await.unwrap();
+    let repository = MusicRepository::new(pool);
+
+    for _ in 0..2 {
+        let report = import_music_file(&repository, &path).await.unwrap();
+        assert_eq!(report.imported, 2);
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(report.skipped[0].line_number, 4);
+        assert_eq!(repository.count().await.unwrap(), 2);
+    }
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,86 @@
+use bili_player::player::music_data::{MusicLineError, parse_music_line, parse_music_lines};
+
+#[test]
+fn parse_plain_line() {
+    let music =
+        parse_music_line("《青花瓷》-04:02-BV1r7411p7R4-321818216-周杰伦-音乐无限").unwrap();
+    assert_eq!(music.title, "青花瓷");
+    assert_eq!(music.duration, 242);
+    assert_eq!(music.bvid, "BV1r7411p7R4");
+    assert_eq!(music.cid, "321818216");
+    assert_eq!(music.artist.as_deref(), Some("周杰伦"));
+    assert_eq!(music.owner, "音乐无限");
+}
+
+#[test]
+fn parse_dashes_in_title_and_names() {
+    let music = parse_music_line("《Love-Story》-1:03:55-BV1oZqqBZEGZ-34856567673-A-Lin-A-Lin官方")
+        .unwrap();
+    assert_eq!(music.title, "Love-Story");
+    assert_eq!(music.duration, 3835);
+    assert_eq!(music.artist.as_deref(), Some("A-Lin"));
+    assert_eq!(music.owner, "A-Lin官方");
+
+    let music =
+        parse_music_line("《晴天》-04:29-BV1oZqqBZEGZ-34856567673-周杰伦-音乐无限").unwrap();
+    assert_eq!(music.artist.as_deref(), Some("周杰伦"));
+    assert_eq!(music.owner, "音乐无限");
+}
+
+#[test]
+fn report_ambiguous_artist_and_owner() {
+    // 上传者不以歌手名开头时无法判断 `-` 属于歌手还是上传者
+    for (line, tail) in [
+        (
+            "《爱情的模样》-04:02-BV1oZqqBZEGZ-1-A-Lin-某UP主",
+            "A-Lin-某UP主",
+        ),
+        (
+            "《晴天》-04:29-BV1oZqqBZEGZ-1-周杰伦-A-Lin官方",
+            "周杰伦-A-Lin官方",
+        ),
+    ] {
+        assert_eq!(
+            parse_music_line(line).unwrap_err(),
+            MusicLineError::AmbiguousArtist(tail.into())
+        );
+    }
+    let music = parse_music_line("《Empire》-04:36-BV1oZqqBZEGZ-1-Jay-Z-Jay-Z官方").unwrap();
+    assert_eq!(music.artist.as_deref(), Some("Jay-Z"));
+    assert_eq!(music.owner, "Jay-Z官方");
+}
+
+#[test]
+fn report_errors_with_line_numbers() {
+    let input = "《晴天》-04:29-BV1oZqqBZEGZ-1-周杰伦-音乐\n\n《晴天》-4m-BV1oZqqBZEGZ-1-周杰伦-音乐\n《晴天》-04:29-BV1oZqq-1-周杰伦-音乐\n《晴天》-04:29-BV1oZqqBZEGZ-abc-周杰伦-音乐\n";
+    let results = parse_music_lines(input.as_bytes()).unwrap();
+    assert_eq!(results.len(), 4);
+    assert!(results[0].is_ok());
+    let errors: Vec<_> = results[1..]
+        .iter()
+        .map(|r| r.clone().unwrap_err())
+        .map(|e| (e.line_number, e.reason))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (3, MusicLineError::InvalidDuration("4m".into())),
+            (4, MusicLineError::MissingBvid),
+            (5, MusicLineError::MissingCid),
+        ]
+    );
+}
+
+#[test]
+fn invalid_utf8_line_does_not_stop_parsing() {
+    let mut input = b"\xff\xfe-04:29\n".to_vec();
+    input.extend_from_slice("《青花瓷》-04:02-BV1r7411p7R4-321818216-周杰伦-音乐无限\n".as_bytes());
+    let results = parse_music_lines(input.as_slice()).unwrap();
+    assert_eq!(results.len(), 2);
+    let error = results[0].clone().unwrap_err();
+    assert_eq!(
+        (error.line_number, error.reason),
+        (1, MusicLineError::InvalidUtf8)
+    );
+    assert_eq!(results[1].clone().unwrap().title, "青花瓷");
+}
@@ -0,0 +1,91 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use bili_player::fetch::passport::{Passport, QrLoginStatus, render_qrcode};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// 启动模拟的通行证服务：前两次轮询分别返回未扫码和已扫码，之后返回登录成功
+async fn mock_passport() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let polls = Arc::new(AtomicUsize::new(0));
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let polls = polls.clone();
+            tokio::spawn(async move {
+                let mut buffer = vec![0; 4096];
+                let n = stream.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..n]).to_string();
+                let (cookies, body) = if request.contains("/qrcode/generate") {
+                    (
+                        "",
+                        r#"{"code":0,"message":"0","data":{"url":"https://account.bilibili.com/h5/account-h5/auth/scan-web?qrcode_key=mock-key","qrcode_key":"mock-key"}}"#,
+                    )
+                } else if !request.contains("qrcode_key=mock-key") {
+                    (
+                        "",
+                        r#"{"code":0,"data":{"code":86038,"message":"二维码已失效"}}"#,
+                    )
+                } else {
+                    match polls.fetch_add(1, Ordering::SeqCst) {
+                        0 => ("", r#"{"code":0,"data":{"code":86101,"message":"未扫码"}}"#),
+                        1 => (
+                            "",
+                            r#"{"code":0,"data":{"code":86090,"message":"二维码已扫码未确认"}}"#,
+                        ),
+                        _ => (
+                            "Set-Cookie: SESSDATA=abc%2C123; Path=/; Domain=bilibili.com; HttpOnly\r\n\
+                             Set-Cookie: bili_jct=csrf; Path=/; Domain=bilibili.com\r\n",
+                            r#"{"code":0,"data":{"code":0,"message":"","url":"https://passport.biligame.com/crossDomain?SESSDATA=abc%2C123&bili_jct=csrf"}}"#,
+                        ),
+                    }
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    cookies,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn qrcode_login_against_mock_passport() {
+    let base_url = mock_passport().await;
+    let client = reqwest::Client::builder().no_proxy().build().unwrap();
+    let passport = Passport::with_base_url(client, &base_url);
+
+    let login = passport.generate_qrcode().await.unwrap();
+    assert_eq!(login.qrcode_key, "mock-key");
+    assert!(render_qrcode(&login.url).unwrap().contains('█'));
+
+    assert_eq!(
+        passport.poll("mock-key").await.unwrap(),
+        QrLoginStatus::Waiting
+    );
+    assert_eq!(
+        passport.poll("mock-key").await.unwrap(),
+        QrLoginStatus::Scanned
+    );
+    match passport.poll("mock-key").await.unwrap() {
+        QrLoginStatus::Confirmed(credentials) => {
+            assert_eq!(credentials.sessdata.as_deref(), Some("abc%2C123"));
+            assert_eq!(credentials.bili_jct.as_deref(), Some("csrf"));
+        }
+        status => panic!("unexpected status: {:?}", status),
+    }
+    assert_eq!(
+        passport.poll("other-key").await.unwrap(),
+        QrLoginStatus::Expired
+    );
+}
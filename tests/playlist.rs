@@ -0,0 +1,219 @@
+use bili_player::player::{
+    model::MusicInfo, play_mode::PlayMode, playlist::PlaylistManager, queue::QueuePosition,
+    reorder::SortKey,
+};
+
+mod common;
+use common::music;
+
+async fn playlist_with(len: usize, mode: PlayMode) -> PlaylistManager {
+    let playlist = PlaylistManager::new();
+    for i in 0..len {
+        playlist.add_music(music(i)).await;
+    }
+    playlist.set_play_mode(mode).await;
+    playlist
+}
+
+#[tokio::test]
+async fn normal_mode_stops_at_both_ends() {
+    let playlist = playlist_with(2, PlayMode::Normal).await;
+    assert!(!playlist.move_to_previous().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(0));
+    playlist.set_current_index(1).await.unwrap();
+    assert!(!playlist.move_to_next().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(1));
+}
+
+#[tokio::test]
+async fn repeat_all_mode_wraps_around() {
+    let playlist = playlist_with(2, PlayMode::RepeatAll).await;
+    playlist.set_current_index(1).await.unwrap();
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(0));
+    assert!(playlist.move_to_previous().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(1));
+}
+
+#[tokio::test]
+async fn shuffle_mode_reshuffles_only_when_looping() {
+    let playlist = playlist_with(3, PlayMode::Shuffle).await;
+    let order = playlist.shuffle_order.lock().await.clone().unwrap();
+    playlist.set_current_index(order[2]).await.unwrap();
+    playlist.set_shuffle_loop(false).await;
+    assert!(!playlist.move_to_next().await.unwrap());
+    playlist.set_shuffle_loop(true).await;
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_ne!(playlist.get_current_index().await, Some(order[2]));
+}
+
+#[tokio::test]
+async fn previous_returns_to_tracks_actually_heard() {
+    let playlist = playlist_with(5, PlayMode::Shuffle).await;
+    let mut heard = vec![playlist.get_current_index().await.unwrap()];
+    for _ in 0..3 {
+        assert!(playlist.move_to_next().await.unwrap());
+        heard.push(playlist.get_current_index().await.unwrap());
+    }
+    // 删除一首听过的歌后，上一首会跳过它
+    let removed = heard[1];
+    playlist.remove_music(removed).await.unwrap();
+    let shifted = |i: usize| if i > removed { i - 1 } else { i };
+    assert!(playlist.move_to_previous().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(shifted(heard[2])));
+    assert!(playlist.move_to_previous().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(shifted(heard[0])));
+    // 剩余的随机顺序仍然覆盖整个列表
+    let mut order = playlist.shuffle_order.lock().await.clone().unwrap();
+    order.sort();
+    assert_eq!(order, (0..4).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn queue_plays_before_playlist_and_resumes_position() {
+    let playlist = playlist_with(3, PlayMode::Normal).await;
+    playlist.queue_music(music(100), QueuePosition::Last).await;
+    playlist.queue_music(music(101), QueuePosition::Next).await;
+    let bvid = |music: Option<MusicInfo>| music.map(|m| m.bvid);
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_eq!(
+        bvid(playlist.get_current_music().await),
+        Some(music(101).bvid)
+    );
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_eq!(
+        bvid(playlist.get_current_music().await),
+        Some(music(100).bvid)
+    );
+    // 队列播完后从播放列表原来的位置继续
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(1));
+    assert!(!playlist.is_playing_queued().await);
+}
+
+#[tokio::test]
+async fn repeat_mode_ignores_queue() {
+    let playlist = playlist_with(2, PlayMode::Repeat).await;
+    playlist.queue_music(music(100), QueuePosition::Last).await;
+    assert!(playlist.move_to_next().await.unwrap());
+    assert_eq!(playlist.get_current_index().await, Some(1));
+    assert_eq!(playlist.get_queue().await.len(), 1);
+}
+
+#[tokio::test]
+async fn removing_songs_drops_them_from_queue() {
+    let playlist = playlist_with(3, PlayMode::Normal).await;
+    playlist.queue_music(music(1), QueuePosition::Last).await;
+    playlist.queue_music(music(100), QueuePosition::Last).await;
+    let removed = playlist.remove_range(1..2).await.unwrap();
+    assert_eq!(removed[0].bvid, music(1).bvid);
+    let queue: Vec<String> = playlist
+        .get_queue()
+        .await
+        .into_iter()
+        .map(|m| m.bvid)
+        .collect();
+    assert_eq!(queue, vec![music(100).bvid]);
+}
+
+#[tokio::test]
+async fn removing_current_song_keeps_queue() {
+    let playlist = playlist_with(3, PlayMode::Normal).await;
+    playlist.set_current_index(0).await.unwrap();
+    playlist.queue_music(music(100), QueuePosition::Last).await;
+    playlist.queue_music(music(101), QueuePosition::Last).await;
+    let (removed, has_next) = playlist.remove_current_range(0..1).await.unwrap();
+    assert_eq!(removed[0].bvid, music(0).bvid);
+    assert!(has_next);
+    assert!(!playlist.is_playing_queued().await);
+    assert_eq!(
+        playlist.get_current_music().await.map(|m| m.bvid),
+        Some(music(1).bvid)
+    );
+    let queue: Vec<String> = playlist
+        .get_queue()
+        .await
+        .into_iter()
+        .map(|m| m.bvid)
+        .collect();
+    assert_eq!(queue, vec![music(100).bvid, music(101).bvid]);
+}
+
+#[tokio::test]
+async fn previous_after_removing_current_song_returns_to_played_song() {
+    let playlist = playlist_with(6, PlayMode::Shuffle).await;
+    let mut played = vec![playlist.get_current_music().await.unwrap().bvid];
+    for _ in 0..2 {
+        assert!(playlist.move_to_next().await.unwrap());
+        played.push(playlist.get_current_music().await.unwrap().bvid);
+    }
+    let current = playlist.get_current_index().await.unwrap();
+    let (_, has_next) = playlist
+        .remove_current_range(current..current + 1)
+        .await
+        .unwrap();
+    assert!(has_next);
+    // 播放历史中只有播放过的歌曲
+    let history = playlist.history.lock().await.clone();
+    assert!(history.iter().all(|m| played.contains(&m.bvid)));
+    assert!(playlist.move_to_previous().await.unwrap());
+    // 上一首是删除前播放的歌曲，而不是删除后补位的歌曲
+    assert_eq!(
+        playlist.get_current_music().await.map(|m| m.bvid),
+        Some(played[1].clone())
+    );
+}
+
+#[tokio::test]
+async fn reorder_keeps_current_track_and_shuffle_order() {
+    let playlist = playlist_with(4, PlayMode::Shuffle).await;
+    playlist.set_current_index(1).await.unwrap();
+    let bvids = |indexes: Vec<usize>, musics: &[MusicInfo]| -> Vec<String> {
+        indexes
+            .into_iter()
+            .map(|i| musics[i].bvid.clone())
+            .collect()
+    };
+    let before = playlist.get_playlist_info(0, 4).await;
+    let order = playlist.shuffle_order.lock().await.clone().unwrap();
+
+    playlist.move_music(1, 3).await.unwrap();
+    playlist.swap_music(0, 2).await.unwrap();
+    playlist.move_to_top(3).await.unwrap();
+
+    let after = playlist.get_playlist_info(0, 4).await;
+    // 当前歌曲和随机播放的先后顺序都跟着歌曲移动
+    assert_eq!(
+        playlist.get_current_music().await.map(|m| m.bvid),
+        Some(music(1).bvid)
+    );
+    assert_eq!(playlist.get_current_index().await, Some(0));
+    let new_order = playlist.shuffle_order.lock().await.clone().unwrap();
+    assert_eq!(bvids(new_order, &after), bvids(order, &before));
+    assert!(playlist.move_music(0, 4).await.is_err());
+}
+
+#[tokio::test]
+async fn sort_by_duration_and_added_order() {
+    let playlist = PlaylistManager::new();
+    for (i, duration) in [(0, 300), (1, 100), (2, 200)] {
+        playlist
+            .add_music(MusicInfo {
+                duration,
+                ..music(i)
+            })
+            .await;
+    }
+    let durations = |musics: Vec<MusicInfo>| musics.iter().map(|m| m.duration).collect::<Vec<_>>();
+    playlist.sort_by(SortKey::Duration, false).await.unwrap();
+    assert_eq!(
+        durations(playlist.get_playlist_info(0, 3).await),
+        [100, 200, 300]
+    );
+    assert_eq!(playlist.get_current_index().await, Some(2));
+    playlist.sort_by(SortKey::Added, true).await.unwrap();
+    assert_eq!(
+        durations(playlist.get_playlist_info(0, 3).await),
+        [200, 100, 300]
+    );
+}
@@ -0,0 +1,59 @@
+use bili_player::{
+    errors::PlayerError,
+    library::{self, playlists::PlaylistRepository, repository::MusicRepository},
+};
+
+mod common;
+use common::music;
+
+#[tokio::test]
+async fn playlists_keep_order_and_survive_library_changes() {
+    let path =
+        std::env::temp_dir().join(format!("bili_player_playlists_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let pool = library::connect(&format!("sqlite:{}", path.display()))
+        .await
+        .unwrap();
+    let musics = MusicRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool);
+
+    let playlist = playlists.create("通勤").await.unwrap();
+    assert!(matches!(
+        playlists.create("通勤").await,
+        Err(PlayerError::AlreadyExists(_))
+    ));
+    for i in [2, 0, 1] {
+        let id = musics.upsert(&music(i)).await.unwrap();
+        playlists.add_music(playlist.id, id).await.unwrap();
+    }
+    let bvids = |records: Vec<library::repository::MusicRecord>| {
+        records.into_iter().map(|r| r.bvid).collect::<Vec<_>>()
+    };
+    let listed = bvids(playlists.list_musics(playlist.id).await.unwrap());
+    assert_eq!(listed, vec![music(2).bvid, music(0).bvid, music(1).bvid]);
+
+    // 从音乐库删除的歌曲不再出现在歌单中
+    musics.soft_delete(&music(0).bvid).await.unwrap();
+    assert!(
+        playlists
+            .remove_music(playlist.id, &music(1).bvid)
+            .await
+            .unwrap()
+    );
+    let listed = bvids(playlists.list_musics(playlist.id).await.unwrap());
+    assert_eq!(listed, vec![music(2).bvid]);
+
+    playlists
+        .set_last_bvid(playlist.id, Some(&music(2).bvid))
+        .await
+        .unwrap();
+    playlists.rename("通勤", "跑步").await.unwrap();
+    let renamed = playlists.get_by_name("跑步").await.unwrap();
+    assert_eq!(renamed.last_bvid, Some(music(2).bvid));
+    assert_eq!(renamed.track_count, 1);
+
+    playlists.delete("跑步").await.unwrap();
+    assert!(playlists.list().await.unwrap().is_empty());
+    assert_eq!(musics.count().await.unwrap(), 2);
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,71 @@
+use bili_player::{
+    library::{self, repository::MusicRepository},
+    player::model::MusicInfo,
+};
+
+mod common;
+use common::music;
+
+#[tokio::test]
+async fn repository_crud_on_memory_database() {
+    let pool = library::connect("sqlite::memory:").await.unwrap();
+    let repository = MusicRepository::new(pool);
+
+    let jay = |i: usize| MusicInfo {
+        artist: Some("周杰伦".into()),
+        owner: "周杰伦官方".into(),
+        ..music(i)
+    };
+    let id = repository.insert(&jay(0)).await.unwrap();
+    assert!(repository.insert(&jay(0)).await.is_err());
+    repository.insert(&jay(1)).await.unwrap();
+    repository.insert(&music(2)).await.unwrap();
+
+    // upsert 按 bvid 更新已有记录
+    let renamed = MusicInfo {
+        title: "晴天".into(),
+        ..jay(0)
+    };
+    assert_eq!(repository.upsert(&renamed).await.unwrap(), id);
+    let record = repository
+        .find_by_bvid(&jay(0).bvid)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.song_name, "晴天");
+    assert_eq!(repository.count().await.unwrap(), 3);
+
+    assert!(repository.like(&music(2).bvid).await.unwrap());
+    let record = repository.find_by_bvid(&music(2).bvid).await.unwrap();
+    assert!(record.unwrap().is_liked);
+    assert!(repository.unlike(&music(2).bvid).await.unwrap());
+    assert!(!repository.like("BV_missing").await.unwrap());
+
+    let page = repository.list_paged(2, 2).await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].bvid, music(2).bvid);
+    assert_eq!(repository.list_by_singer("周杰伦").await.unwrap().len(), 2);
+    assert_eq!(repository.list_by_author("up").await.unwrap().len(), 1);
+
+    // 软删除后查不到，再次 upsert 时恢复
+    assert!(repository.soft_delete(&jay(1).bvid).await.unwrap());
+    assert!(!repository.soft_delete(&jay(1).bvid).await.unwrap());
+    assert!(
+        repository
+            .find_by_bvid(&jay(1).bvid)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(repository.list_by_singer("周杰伦").await.unwrap().len(), 1);
+    assert_eq!(repository.count().await.unwrap(), 2);
+    repository.upsert(&jay(1)).await.unwrap();
+    let bvids: Vec<String> = repository
+        .list_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|record| record.bvid)
+        .collect();
+    assert_eq!(bvids, vec![jay(0).bvid, jay(1).bvid, music(2).bvid]);
+}
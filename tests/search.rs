@@ -0,0 +1,36 @@
+use bili_player::player::{
+    model::MusicInfo,
+    search::{MusicQuery, match_score},
+};
+
+mod common;
+
+fn music(title: &str, owner: &str) -> MusicInfo {
+    MusicInfo {
+        title: title.into(),
+        owner: owner.into(),
+        ..common::music(0)
+    }
+}
+
+#[test]
+fn match_score_ranks_exact_prefix_substring_fuzzy() {
+    let exact = match_score("Hello", "hello").unwrap();
+    let prefix = match_score("Hello World", "hello").unwrap();
+    let substring = match_score("Say Hello", "hello").unwrap();
+    let fuzzy = match_score("Hey Lovely Llama", "hll").unwrap();
+    assert!(exact > prefix && prefix > substring && substring > fuzzy);
+    assert_eq!(match_score("Hello", "xyz"), None);
+}
+
+#[test]
+fn query_requires_every_condition_to_match() {
+    let query = MusicQuery {
+        title: Some("晴天".into()),
+        owner: Some("周杰伦".into()),
+        ..Default::default()
+    };
+    assert!(query.score(&music("晴天", "周杰伦官方")).is_some());
+    assert!(query.score(&music("晴天", "某翻唱")).is_none());
+    assert!(MusicQuery::default().is_empty());
+}
@@ -0,0 +1,73 @@
+use bili_player::{errors::PlayerError, player::playback::SeekTarget};
+use gstreamer::ClockTime;
+
+const POSITION: Option<ClockTime> = Some(ClockTime::from_seconds(60));
+const DURATION: Option<ClockTime> = Some(ClockTime::from_seconds(200));
+
+#[test]
+fn resolve_absolute_and_relative() {
+    let target = SeekTarget::Absolute(ClockTime::from_seconds(90));
+    assert_eq!(
+        target.resolve(POSITION, DURATION).unwrap(),
+        ClockTime::from_seconds(90)
+    );
+    assert_eq!(
+        SeekTarget::Relative(15_000)
+            .resolve(POSITION, DURATION)
+            .unwrap(),
+        ClockTime::from_seconds(75)
+    );
+    assert_eq!(
+        SeekTarget::Relative(-15_000)
+            .resolve(POSITION, DURATION)
+            .unwrap(),
+        ClockTime::from_seconds(45)
+    );
+    // 往回跳过头时停在开头
+    assert_eq!(
+        SeekTarget::Relative(-120_000)
+            .resolve(POSITION, DURATION)
+            .unwrap(),
+        ClockTime::ZERO
+    );
+    assert!(matches!(
+        SeekTarget::Relative(1000).resolve(None, DURATION),
+        Err(PlayerError::StateTransition(_))
+    ));
+}
+
+#[test]
+fn resolve_percent() {
+    assert_eq!(
+        SeekTarget::Percent(25.0)
+            .resolve(POSITION, DURATION)
+            .unwrap(),
+        ClockTime::from_seconds(50)
+    );
+    assert_eq!(
+        SeekTarget::Percent(100.0)
+            .resolve(POSITION, DURATION)
+            .unwrap(),
+        ClockTime::from_seconds(200)
+    );
+    assert!(matches!(
+        SeekTarget::Percent(120.0).resolve(POSITION, DURATION),
+        Err(PlayerError::SeekOutOfRange(_))
+    ));
+    assert!(matches!(
+        SeekTarget::Percent(50.0).resolve(POSITION, None),
+        Err(PlayerError::StateTransition(_))
+    ));
+}
+
+#[test]
+fn seeking_past_duration_is_an_error() {
+    assert!(matches!(
+        SeekTarget::Absolute(ClockTime::from_seconds(201)).resolve(POSITION, DURATION),
+        Err(PlayerError::SeekOutOfRange(_))
+    ));
+    assert!(matches!(
+        SeekTarget::Relative(150_000).resolve(POSITION, DURATION),
+        Err(PlayerError::SeekOutOfRange(_))
+    ));
+}
@@ -0,0 +1,39 @@
+use bili_player::library::{
+    self,
+    playlists::PlaylistRepository,
+    session::{SessionRecord, SessionRepository},
+};
+
+#[tokio::test]
+async fn session_is_overwritten_and_falls_back_to_library() {
+    let path = std::env::temp_dir().join(format!("bili_player_session_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let pool = library::connect(&format!("sqlite:{}", path.display()))
+        .await
+        .unwrap();
+    let sessions = SessionRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool);
+    assert_eq!(sessions.load().await.unwrap(), None);
+
+    let playlist = playlists.create("通勤").await.unwrap();
+    let mut session = SessionRecord {
+        playlist_id: Some(playlist.id),
+        current_bvid: Some("BV1r7411p7R4".into()),
+        play_mode: "shuffle".into(),
+        shuffle_loop: false,
+        volume: 35,
+        position_ms: 1000,
+        library_bvid: Some("BV1rU4y1Y71M".into()),
+    };
+    sessions.save(&session).await.unwrap();
+    session.position_ms = 90_000;
+    sessions.save(&session).await.unwrap();
+    assert_eq!(sessions.load().await.unwrap(), Some(session.clone()));
+
+    // 歌单被删除后回到音乐库
+    playlists.delete("通勤").await.unwrap();
+    let restored = sessions.load().await.unwrap().unwrap();
+    assert_eq!(restored.playlist_id, None);
+    assert_eq!(restored.position_ms, 90_000);
+    let _ = std::fs::remove_file(&path);
+}
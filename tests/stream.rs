@@ -0,0 +1,66 @@
+use bili_player::fetch::stream::{AudioPreference, AudioQuality, StreamSelector, parse_dash_audio};
+use serde_json::json;
+
+#[test]
+fn select_audio_stream_by_preference() {
+    let data = json!({
+        "dash": {
+            "audio": [
+                {"id": 30280, "baseUrl": "https://a/30280.m4s", "base_url": "https://a/30280.m4s",
+                 "backupUrl": ["https://b/30280.m4s"], "bandwidth": 319173, "codecs": "mp4a.40.2"},
+                {"id": 30232, "baseUrl": "https://a/30232.m4s", "backupUrl": null,
+                 "bandwidth": 132491, "codecs": "mp4a.40.2"},
+                {"id": 30216, "baseUrl": "https://a/30216.m4s", "bandwidth": 67125, "codecs": "mp4a.40.2"}
+            ],
+            "dolby": {"type": 0, "audio": null},
+            "flac": {"display": true, "audio": {"id": 30251, "baseUrl": "https://a/30251.m4s",
+                     "backupUrl": [], "bandwidth": 1587000, "codecs": "fLaC"}}
+        }
+    });
+    let streams = parse_dash_audio(&data).unwrap();
+    assert_eq!(streams.len(), 4);
+    assert_eq!(streams[0].backup_urls, ["https://b/30280.m4s"]);
+    assert!(streams[1].backup_urls.is_empty());
+
+    let pick = |preference| {
+        StreamSelector::new(preference)
+            .select(&streams)
+            .and_then(|stream| stream.quality())
+    };
+    assert_eq!(pick(AudioPreference::Max), Some(AudioQuality::HiRes));
+    // 默认不选需要额外插件的 Hi-Res 音轨
+    assert_eq!(
+        StreamSelector::default()
+            .select(&streams)
+            .and_then(|stream| stream.quality()),
+        Some(AudioQuality::VeryHigh)
+    );
+    assert_eq!(
+        pick(AudioPreference::Bitrate(192)),
+        Some(AudioQuality::VeryHigh)
+    );
+    assert_eq!(
+        pick(AudioPreference::Bitrate(150)),
+        Some(AudioQuality::High)
+    );
+    // 没有满足码率上限的音轨时退回码率最低的一条
+    assert_eq!(
+        pick(AudioPreference::Bitrate(32)),
+        Some(AudioQuality::Standard)
+    );
+    assert_eq!(
+        pick(AudioPreference::DataSaver),
+        Some(AudioQuality::Standard)
+    );
+
+    assert_eq!(
+        AudioPreference::from_string("192K"),
+        Some(AudioPreference::Bitrate(192))
+    );
+    assert_eq!(
+        AudioPreference::from_string("data_saver"),
+        Some(AudioPreference::DataSaver)
+    );
+    assert_eq!(AudioPreference::from_string("loud"), None);
+    assert!(parse_dash_audio(&json!({"dash": {"audio": []}})).is_err());
+}
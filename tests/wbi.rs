@@ -0,0 +1,27 @@
+use bili_player::fetch::wbi::{WbiKeys, sign_params};
+
+#[test]
+fn sign_params_with_fixed_keys() {
+    let keys = WbiKeys::from_urls(
+        "https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png",
+        "https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png",
+    )
+    .unwrap();
+    assert_eq!(keys.img_key, "7cd084941338484aae1ad9425b84077c");
+    let mixin_key = keys.mixin_key();
+    assert_eq!(mixin_key, "ea1db124af3c7062474693fa704f4ff8");
+
+    let query = sign_params(
+        &[("foo", "114"), ("bar", "514"), ("zab", "1919810")],
+        &mixin_key,
+        1702204169,
+    );
+    assert_eq!(
+        query,
+        "bar=514&foo=114&wts=1702204169&zab=1919810&w_rid=8f6f2b5b3d485fe1886cec6a0be8c5d4"
+    );
+    // 值中的 !'()* 会被去掉，其余字符按 RFC 3986 编码
+    let query = sign_params(&[("keyword", "a b(c)!")], &mixin_key, 1702204169);
+    assert!(query.starts_with("keyword=a%20bc&wts=1702204169&w_rid="));
+    assert!(WbiKeys::from_urls("https://i0.hdslb.com/bfs/wbi/", "").is_none());
+}